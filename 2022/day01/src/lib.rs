@@ -1,6 +1,6 @@
-use std::fs;
+use aocutils::{Result, Solution};
 
-fn total_calories(list: &String) -> Vec<i32> {
+fn total_calories(list: &str) -> Vec<i32> {
     // first split by double lines for each elf, for each elf split into lines
     // to get each item and sum them
     let mut calories = list.split("\n\n")
@@ -13,24 +13,39 @@ fn total_calories(list: &String) -> Vec<i32> {
     calories
 }
 
-fn highest_calories(list: &String) -> i32 {
+fn highest_calories(list: &str) -> i32 {
     let calories = total_calories(list);
     // get the last item in the list to get the highest
     *calories.last().unwrap()
 }
 
-fn total_top_calories(list: &String, n: usize) -> i32 {
+fn total_top_calories(list: &str, n: usize) -> i32 {
     let calories = total_calories(list);
     // reverse and take the first n, then sum them to get the top n highest
     calories.iter().rev().take(n).sum()
 }
 
-fn main() {
-    let input1 = fs::read_to_string("inputs/input.txt")
-        .expect("should be able to read input");
+pub struct Day01;
 
-    println!("Part 1: {}", highest_calories(&input1));
-    println!("Part 2: {}", total_top_calories(&input1, 3));
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input = String;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32> {
+        Ok(highest_calories(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<i32> {
+        Ok(total_top_calories(input, 3))
+    }
 }
 
 #[cfg(test)]
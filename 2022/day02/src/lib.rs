@@ -1,4 +1,4 @@
-use std::fs;
+use aocutils::{Result, Solution};
 
 // slice with all shapes and outcomes. elements can be found by using 2 values
 // to find the missing one
@@ -14,21 +14,12 @@ const OUTCOMES: [(char, char, i32); 9] = [
     ('C', 'Y', 0), // scissors + rock = loss
     ('C', 'Z', 3) // scissors + scissors = draw
 ];
-
-fn main() {
-    let input = parse_guide(&fs::read_to_string("inputs/input.txt")
-            .expect("should be able to read input"));
-
-    println!("Part 1: {}", input.iter().map(|round| score_part_1(&round)).sum::<i32>());
-    println!("Part 2: {}", input.iter().map(|round| score_part_2(&round)).sum::<i32>());
-}
-
-fn parse_guide(guide: &String) -> Vec<(char, char)> {
+fn parse_guide(guide: &str) -> Vec<(char, char)> {
     // split guide by each line, then convert each line to a tuple with the
     // first and second move
     guide.lines().map(|line| {
         let mut chars = line.chars();
-        (chars.next().unwrap(), chars.skip(1).next().unwrap())
+        (chars.next().unwrap(), chars.nth(1).unwrap())
     }).collect()
 }
 
@@ -71,6 +62,29 @@ fn score_part_2(round: &(char, char)) -> i32 {
     shape_score(&p1) + outcome_score
 }
 
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    type Input = Vec<(char, char)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_guide(input))
+    }
+
+    fn part1(guide: &Self::Input) -> Result<i32> {
+        Ok(guide.iter().map(score_part_1).sum())
+    }
+
+    fn part2(guide: &Self::Input) -> Result<i32> {
+        Ok(guide.iter().map(score_part_2).sum())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
-use std::fs;
+use aocutils::{Result, Solution};
 
-fn parse_bags(input: &String) -> Vec<(&str, &str)> {
+fn parse_bags(input: &str) -> Vec<(&str, &str)> {
     // read each line and split each in half
     input.lines().map(|line| line.split_at(line.len() / 2)).collect()
 }
@@ -18,7 +18,7 @@ fn get_priority(c: char) -> u8 {
     priority
 }
 
-fn multi_intersect(items: &Vec<&str>) -> Vec<char> {
+fn multi_intersect(items: &[&str]) -> Vec<char> {
     // convert all of the strs into hashsets of chars
     let mut hashsets = items.iter().map(|i| i.chars().collect::<HashSet<char>>());
     // get the first hashset
@@ -34,32 +34,45 @@ fn multi_intersect(items: &Vec<&str>) -> Vec<char> {
 fn priority_totals(bags: Vec<(&str, &str)>) -> i32 {
     bags.iter().map(|bag| {
         // convert the tuple to a vec
-        let mut bags_vec = Vec::new();
-        bags_vec.push(bag.0);
-        bags_vec.push(bag.1);
+        let bags_vec = vec![bag.0, bag.1];
 
         // get the common characters between both bags and convert them to priorities
         multi_intersect(&bags_vec).iter().map(|c| get_priority(*c) as i32).sum::<i32>()
     }).sum()
 }
 
-fn badge_priority_totals(input: &String) -> i32 {
+fn badge_priority_totals(input: &str) -> i32 {
     let lines = input.lines().collect::<Vec<&str>>();
     // group the lines into 3s
     let groups = lines.chunks(3).collect::<Vec<&[&str]>>();
 
     groups.iter().map(|group| {
         // for each group, find the common characters and convert them to priorities
-        multi_intersect(&group.to_vec()).iter().map(|c| get_priority(*c) as i32).sum::<i32>()
+        multi_intersect(group).iter().map(|c| get_priority(*c) as i32).sum::<i32>()
     }).sum()
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let bags = parse_bags(&input);
+pub struct Day03;
 
-    println!("Part 1: {}", priority_totals(bags));
-    println!("Part 2: {}", badge_priority_totals(&input));
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Input = String;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32> {
+        Ok(priority_totals(parse_bags(input)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<i32> {
+        Ok(badge_priority_totals(input))
+    }
 }
 
 #[cfg(test)]
@@ -82,21 +95,19 @@ mod tests {
 
     #[test]
     fn get_priority_example() {
-        let input = vec!['p', 'L', 'P', 'v', 't', 's'];
+        let input = ['p', 'L', 'P', 'v', 't', 's'];
         assert_eq!(input.iter().map(|x| get_priority(*x)).collect::<Vec<_>>(),
             vec![16_u8, 38_u8, 42_u8, 22_u8, 20_u8, 19_u8]);
     }
 
     #[test]
     fn multi_intersect_example() {
-        assert_eq!(vec![
-            vec!["vJrwpWtwJgWr", "hcsFMMfFFhFp"],
+        assert_eq!([vec!["vJrwpWtwJgWr", "hcsFMMfFFhFp"],
             vec!["jqHRNqRjqzjGDLGL", "rsFMfFZSrLrFZsSL"],
             vec!["PmmdzqPrV", "vPwwTWBwg"],
             vec!["wMqvLMZHhHMvwLH", "jbvcjnnSBnvTQFn"],
             vec!["ttgJtRGJ", "QctTZtZT"],
-            vec!["CrZsJsPPZsGz", "wwsLwLmpwMDw"]
-        ].iter().map(|group| multi_intersect(group)).collect::<Vec<Vec<char>>>(),
+            vec!["CrZsJsPPZsGz", "wwsLwLmpwMDw"]].iter().map(|group| multi_intersect(group)).collect::<Vec<Vec<char>>>(),
         vec![
             vec!['p'], vec!['L'], vec!['P'], vec!['v'], vec!['t'], vec!['s']
         ]);
@@ -119,4 +130,4 @@ mod tests {
         let example = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
         assert_eq!(badge_priority_totals(&example), 70);
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use std::fs;
+use aocutils::{Result, Solution};
 
 fn full_overlap(a: (i32, i32), b: (i32, i32)) -> bool {
     // does a fully contain b, or vice-versa
@@ -11,7 +11,7 @@ fn any_overlap(a: (i32, i32), b: (i32, i32)) -> bool {
     full_overlap(a, b) || (a.1 >= b.0 && a.0 <= b.0) || (a.0 <= b.1 && a.1 >= b.1)
 }
 
-fn parse_input(input: &String) -> Vec<((i32, i32), (i32, i32))> {
+fn parse_input(input: &str) -> Vec<((i32, i32), (i32, i32))> {
     // split into lines, then by comma to get each section
     input.lines().map(|line| line.split(',')
         .map(|assignment| {
@@ -23,19 +23,28 @@ fn parse_input(input: &String) -> Vec<((i32, i32), (i32, i32))> {
     ).collect::<Vec<_>>()
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let assignments = parse_input(&input);
-    // apply overlap functions to all assignments, filter by true values then count items
-    let full_overlaps = assignments.iter()
-        .map(|assignment| full_overlap(assignment.0, assignment.1))
-        .filter(|x| *x).count();
-    let any_overlaps = assignments.iter()
-        .map(|assignment| any_overlap(assignment.0, assignment.1))
-        .filter(|x| *x).count();
+pub struct Day04;
 
-    println!("Part 1: {}", full_overlaps);
-    println!("Part 2: {}", any_overlaps);
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input = Vec<((i32, i32), (i32, i32))>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(assignments: &Self::Input) -> Result<usize> {
+        // apply overlap functions to all assignments, filter by true values then count items
+        Ok(assignments.iter().filter(|assignment| full_overlap(assignment.0, assignment.1)).count())
+    }
+
+    fn part2(assignments: &Self::Input) -> Result<usize> {
+        Ok(assignments.iter().filter(|assignment| any_overlap(assignment.0, assignment.1)).count())
+    }
 }
 
 #[cfg(test)]
@@ -58,14 +67,12 @@ mod tests {
 
     #[test]
     fn full_overlap_example() {
-        assert_eq!(vec![
-            ((2, 4), (6, 8)),
+        assert_eq!([((2, 4), (6, 8)),
             ((2, 3), (4, 5)),
             ((5, 7), (7, 9)),
             ((2, 8), (3, 7)),
             ((6, 6), (4, 6)),
-            ((2, 6), (4, 8))
-        ].iter().map(|x| full_overlap(x.0, x.1)).collect::<Vec<_>>(), vec![
+            ((2, 6), (4, 8))].iter().map(|x| full_overlap(x.0, x.1)).collect::<Vec<_>>(), vec![
             false,
             false,
             false,
@@ -77,14 +84,12 @@ mod tests {
 
     #[test]
     fn any_overlap_example() {
-        assert_eq!(vec![
-            ((2, 4), (6, 8)),
+        assert_eq!([((2, 4), (6, 8)),
             ((2, 3), (4, 5)),
             ((5, 7), (7, 9)),
             ((2, 8), (3, 7)),
             ((6, 6), (4, 6)),
-            ((2, 6), (4, 8))
-        ].iter().map(|x| any_overlap(x.0, x.1)).collect::<Vec<_>>(), vec![
+            ((2, 6), (4, 8))].iter().map(|x| any_overlap(x.0, x.1)).collect::<Vec<_>>(), vec![
             false,
             false,
             true,
@@ -93,4 +98,4 @@ mod tests {
             true
         ]);
     }
-}
\ No newline at end of file
+}
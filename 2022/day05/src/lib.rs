@@ -1,51 +1,41 @@
-use std::fs;
+use aocutils::{parse::{FromInput, Span}, Result, Solution};
 
-#[derive(Debug, PartialEq, Eq)]
-struct MoveInstruction {
+#[derive(Debug, PartialEq, Eq, FromInput)]
+#[aoc(pattern = "move {quantity} from {from} to {to}")]
+pub struct MoveInstruction {
     quantity: usize,
     from: usize,
     to: usize
 }
 
-fn parse_input(input: &String) -> (Vec<Vec<char>>, Vec<MoveInstruction>) {
-    let (raw_crates, raw_instructions) = input.split_once("\n\n").unwrap();
-    let raw_crates = raw_crates.lines();
+fn parse_input(input: &str) -> Result<(Vec<Vec<char>>, Vec<MoveInstruction>)> {
+    let (raw_crates, raw_instructions) = Span::new(input).split_once("\n\n")?;
+    let raw_crates = raw_crates.as_str().lines();
 
     // get the last line of crates (number labels) then split by whitespace and get the last
-    let stacks = raw_crates.clone().rev().next().unwrap().split_whitespace()
-        .rev().next().unwrap().parse::<usize>().unwrap();
+    let stacks = raw_crates.clone().next_back().unwrap().split_whitespace().next_back().unwrap().parse::<usize>().unwrap();
 
     let mut crates: Vec<Vec<char>> = vec![vec![]; stacks];
-    let mut instructions: Vec<MoveInstruction> = Vec::new();
 
     // iterate through each crate line, starting from the bottom but skipping the number labels
     for line in raw_crates.rev().skip(1) {
-        for stack in 0..stacks {
+        for (stack, crate_stack) in crates.iter_mut().enumerate() {
             // get the character at 4n+1 for the character at the given stack
-            if let Some(c) = line.clone().chars().nth((4*stack)+1) {
+            if let Some(c) = line.chars().nth((4*stack)+1) {
                 if c != ' ' {
                     // add the character to the crates if it is not none or a space
-                    crates[stack].push(c);
+                    crate_stack.push(c);
                 }
             }
         }
     }
 
-    for line in raw_instructions.lines() {
-        let mut parts = line.split_whitespace().skip(1).step_by(2)
-            .map(|n| n.parse::<usize>().unwrap());
-        
-        instructions.push(MoveInstruction {
-            quantity: parts.next().unwrap(),
-            from: parts.next().unwrap(),
-            to: parts.next().unwrap()
-        });
-    }
+    let instructions = raw_instructions.map_lines(MoveInstruction::from_span)?;
 
-    (crates, instructions)
+    Ok((crates, instructions))
 }
 
-fn rearrange_stacks(crates: &mut Vec<Vec<char>>, instructions: &Vec<MoveInstruction>, reverse: bool) {
+fn rearrange_stacks(crates: &mut [Vec<char>], instructions: &[MoveInstruction], reverse: bool) {
     for instruction in instructions {
         // calculate the length of the from stack minus the quantity to move (i.e. final length of stack)
         let from_len = crates[instruction.from - 1].len().saturating_sub(instruction.quantity);
@@ -63,21 +53,35 @@ fn rearrange_stacks(crates: &mut Vec<Vec<char>>, instructions: &Vec<MoveInstruct
     }
 }
 
-fn top_crates(crates: &Vec<Vec<char>>) -> Vec<char> {
+fn top_crates(crates: &[Vec<char>]) -> Vec<char> {
     crates.iter().map(|stack| *stack.last().unwrap()).collect()
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let (crates, instructions) = parse_input(&input);
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    type Input = (Vec<Vec<char>>, Vec<MoveInstruction>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
 
-    let mut part1_crates = crates.clone();
-    rearrange_stacks(&mut part1_crates, &instructions, true);
-    println!("Part 1: {}", top_crates(&part1_crates).iter().collect::<String>());
+    fn part1((crates, instructions): &Self::Input) -> Result<String> {
+        let mut crates = crates.clone();
+        rearrange_stacks(&mut crates, instructions, true);
+        Ok(top_crates(&crates).iter().collect())
+    }
 
-    let mut part2_crates = crates.clone();
-    rearrange_stacks(&mut part2_crates, &instructions, false);
-    println!("Part 2: {}", top_crates(&part2_crates).iter().collect::<String>());
+    fn part2((crates, instructions): &Self::Input) -> Result<String> {
+        let mut crates = crates.clone();
+        rearrange_stacks(&mut crates, instructions, false);
+        Ok(top_crates(&crates).iter().collect())
+    }
 }
 
 #[cfg(test)]
@@ -88,7 +92,7 @@ mod tests {
     #[test]
     fn parse_input_example() {
         let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
-        let (crates, instructions) = parse_input(&input);
+        let (crates, instructions) = parse_input(&input).unwrap();
         assert_eq!(crates, vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
         assert_eq!(instructions, vec![
             MoveInstruction { quantity: 1, from: 2, to: 1 },
@@ -102,12 +106,10 @@ mod tests {
     fn rearrange_stacks_part1_example() {
         let mut crates = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
 
-        rearrange_stacks(&mut crates, &vec![
-                MoveInstruction { quantity: 1, from: 2, to: 1 },
+        rearrange_stacks(&mut crates, &[MoveInstruction { quantity: 1, from: 2, to: 1 },
                 MoveInstruction { quantity: 3, from: 1, to: 3 },
                 MoveInstruction { quantity: 2, from: 2, to: 1 },
-                MoveInstruction { quantity: 1, from: 1, to: 2 },
-        ], true);
+                MoveInstruction { quantity: 1, from: 1, to: 2 }], true);
 
         assert_eq!(crates, vec![vec!['C'], vec!['M'], vec!['P', 'D', 'N', 'Z']]);
     }
@@ -116,18 +118,16 @@ mod tests {
     fn rearrange_stacks_part2_example() {
         let mut crates = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
 
-        rearrange_stacks(&mut crates, &vec![
-            MoveInstruction { quantity: 1, from: 2, to: 1 },
+        rearrange_stacks(&mut crates, &[MoveInstruction { quantity: 1, from: 2, to: 1 },
             MoveInstruction { quantity: 3, from: 1, to: 3 },
             MoveInstruction { quantity: 2, from: 2, to: 1 },
-            MoveInstruction { quantity: 1, from: 1, to: 2 },
-        ], false);
+            MoveInstruction { quantity: 1, from: 1, to: 2 }], false);
 
         assert_eq!(crates, vec![vec!['M'], vec!['C'], vec!['P', 'Z', 'N', 'D']]);
     }
 
     #[test]
     fn top_crates_example() {
-        assert_eq!(top_crates(&vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]), vec!['N', 'D', 'P']);
+        assert_eq!(top_crates(&[vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]), vec!['N', 'D', 'P']);
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,5 @@
-use std::fs;
 use std::collections::HashSet;
+use aocutils::{Result, Solution};
 
 fn get_marker(buf: &str, n: usize) -> Option<usize> {
     let chars = buf.chars().collect::<Vec<char>>();
@@ -12,16 +12,33 @@ fn get_marker(buf: &str, n: usize) -> Option<usize> {
     None
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
+pub struct Day06;
 
-    println!("Part 1: {}", get_marker(&input, 4).unwrap());
-    println!("Part 2: {}", get_marker(&input, 14).unwrap());
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(get_marker(input, 4).expect("should find a start-of-packet marker"))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(get_marker(input, 14).expect("should find a start-of-message marker"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    
 
     #[test]
     fn get_start_of_packet_marker_examples() {
@@ -42,4 +59,4 @@ mod tests {
         assert_eq!(get_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", n), Some(29));
         assert_eq!(get_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", n), Some(26));
     }
-}
\ No newline at end of file
+}
@@ -1,8 +1,8 @@
-use std::fs;
 use counter::Counter;
+use aocutils::{Result, Solution};
 
 #[derive(Debug, PartialEq, Eq)]
-struct File {
+pub struct File {
     full_path: String,
     size: i32
 }
@@ -35,9 +35,9 @@ fn parse_tree(input: &str) -> Vec<File> {
             let parts = line.split_whitespace().collect::<Vec<&str>>();
             // the first part is either "dir" or the file size
             // dirs can be ignored because the prefix tells where the files are
-            if parts.get(0).unwrap() != &"dir" {
+            if parts.first().unwrap() != &"dir" {
                 // add a new item with the prefix + the file name and file size
-                items.push(File { full_path: prefix.clone() + parts.get(1).unwrap(), size: parts.get(0).unwrap().parse().unwrap() })
+                items.push(File { full_path: prefix.clone() + parts.get(1).unwrap(), size: parts.first().unwrap().parse().unwrap() })
             }
         }
     }
@@ -46,7 +46,7 @@ fn parse_tree(input: &str) -> Vec<File> {
 }
 
 // count the sizes of each directory (e.g. [('/a/b', 1234), ('/a', 1276)])
-fn count_dirs(tree: &Vec<File>) -> Counter<String> {
+fn count_dirs(tree: &[File]) -> Counter<String> {
     // python-style counter - there's maybe something that doesn't involve libraries but this is quick and easy
     let mut counter: Counter<String> = Counter::new();
 
@@ -66,33 +66,47 @@ fn count_dirs(tree: &Vec<File>) -> Counter<String> {
 }
 
 // find the smallest directories underneath a given threshold
-fn smallest_dirs(tree: &Vec<File>, threshold: i32) -> i32 {
+fn smallest_dirs(tree: &[File], threshold: i32) -> i32 {
     let counter = count_dirs(tree);
     counter.most_common().iter().filter(|x| (x.1 as i32) <= threshold).map(|x| x.1 as i32).sum::<i32>()
 }
 
 // calculate the amount of space that needs to be freed given the total space and space needed
-fn space_to_free(tree: &Vec<File>, available_space: i32, required_space: i32) -> i32 {
+fn space_to_free(tree: &[File], available_space: i32, required_space: i32) -> i32 {
     let used_space: i32 = tree.iter().map(|x| x.size).sum();
     let unused_space = available_space - used_space;
     required_space - unused_space
 }
 
 // for part 2, find the best directory to delete - closest to the amount that needs to be freed
-fn dir_to_delete(tree: &Vec<File>, to_free: i32) -> i32 {
+fn dir_to_delete(tree: &[File], to_free: i32) -> i32 {
     let counter = count_dirs(tree);
     // filter out directories under the amount to be freed, then take the smallest of what is left
     counter.iter().map(|x| *x.1 as i32).filter(|x| *x > to_free).min().unwrap()
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let tree = parse_tree(&input);
+pub struct Day07;
 
-    println!("Part 1: {}", smallest_dirs(&tree, 100000));
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
 
-    let to_free = space_to_free(&tree, 70000000, 30000000);
-    println!("Part 2: {}", dir_to_delete(&tree, to_free))
+    type Input = Vec<File>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_tree(input))
+    }
+
+    fn part1(tree: &Self::Input) -> Result<i32> {
+        Ok(smallest_dirs(tree, 100000))
+    }
+
+    fn part2(tree: &Self::Input) -> Result<i32> {
+        let to_free = space_to_free(tree, 70000000, 30000000);
+        Ok(dir_to_delete(tree, to_free))
+    }
 }
 
 #[cfg(test)]
@@ -120,8 +134,7 @@ mod tests {
 
     #[test]
     fn test_smallest_dirs() {
-        assert_eq!(smallest_dirs(&vec![
-            File { full_path: "/a/e/i".to_string(), size: 584 },
+        assert_eq!(smallest_dirs(&[File { full_path: "/a/e/i".to_string(), size: 584 },
             File { full_path: "/a/f".to_string(), size: 29116 },
             File { full_path: "/a/g".to_string(), size: 2557 },
             File { full_path: "/a/h.lst".to_string(), size: 62596 },
@@ -130,14 +143,12 @@ mod tests {
             File { full_path: "/d/j".to_string(), size: 4060174 },
             File { full_path: "/d/d.log".to_string(), size: 8033020 },
             File { full_path: "/d/d.ext".to_string(), size: 5626152 },
-            File { full_path: "/d/k".to_string(), size: 7214296 }
-        ], 100000), 95437);
+            File { full_path: "/d/k".to_string(), size: 7214296 }], 100000), 95437);
     }
 
     #[test]
     fn test_space_to_free() {
-        assert_eq!(space_to_free(&vec![
-            File { full_path: "/a/e/i".to_string(), size: 584 },
+        assert_eq!(space_to_free(&[File { full_path: "/a/e/i".to_string(), size: 584 },
             File { full_path: "/a/f".to_string(), size: 29116 },
             File { full_path: "/a/g".to_string(), size: 2557 },
             File { full_path: "/a/h.lst".to_string(), size: 62596 },
@@ -146,14 +157,12 @@ mod tests {
             File { full_path: "/d/j".to_string(), size: 4060174 },
             File { full_path: "/d/d.log".to_string(), size: 8033020 },
             File { full_path: "/d/d.ext".to_string(), size: 5626152 },
-            File { full_path: "/d/k".to_string(), size: 7214296 }
-        ], 70000000, 30000000), 8381165);
+            File { full_path: "/d/k".to_string(), size: 7214296 }], 70000000, 30000000), 8381165);
     }
 
     #[test]
     fn test_dir_to_delete() {
-        assert_eq!(dir_to_delete(&vec![
-            File { full_path: "/a/e/i".to_string(), size: 584 },
+        assert_eq!(dir_to_delete(&[File { full_path: "/a/e/i".to_string(), size: 584 },
             File { full_path: "/a/f".to_string(), size: 29116 },
             File { full_path: "/a/g".to_string(), size: 2557 },
             File { full_path: "/a/h.lst".to_string(), size: 62596 },
@@ -162,7 +171,6 @@ mod tests {
             File { full_path: "/d/j".to_string(), size: 4060174 },
             File { full_path: "/d/d.log".to_string(), size: 8033020 },
             File { full_path: "/d/d.ext".to_string(), size: 5626152 },
-            File { full_path: "/d/k".to_string(), size: 7214296 }
-        ], 8381165), 24933642);
+            File { full_path: "/d/k".to_string(), size: 7214296 }], 8381165), 24933642);
     }
-}
\ No newline at end of file
+}
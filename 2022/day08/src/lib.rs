@@ -0,0 +1,166 @@
+use aocutils::{Grid, Result, Solution};
+
+type HeightMap = Grid<u8>;
+
+// convert input to a grid of tree heights
+fn parse_map(input: &str) -> Result<HeightMap> {
+    Grid::try_parse(input, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+// get the trees in up, down, left, right directions
+fn tree_lines(map: &HeightMap, x: usize, y: usize) -> [Vec<u8>; 4] {
+    let (left, right) = map.row(y).split_at(x);
+    let col = map.column(x).copied().collect::<Vec<u8>>();
+    let (up, down) = col.split_at(y);
+
+    let down_vec = down[1..].to_vec();
+    let right_vec = right[1..].to_vec();
+    let mut up_vec = up.to_vec();
+    let mut left_vec = left.to_vec();
+
+    // reverse the up and left directions as they need to be searched from the outside in
+    left_vec.reverse();
+    up_vec.reverse();
+
+    [up_vec, down_vec, left_vec, right_vec]
+}
+
+// given coordinates of the tree, is it visible from the outside?
+fn tree_visible(map: &HeightMap, x: usize, y: usize) -> bool {
+    let directions = tree_lines(map, x, y);
+    // height of the given tree
+    let target = map[(x, y)];
+
+    // in any direction see if all trees smaller than the given one
+    directions.iter().any(|direction| direction.iter().all(|x| *x < target))
+}
+
+// count the total number of trees that are visible from the outside
+fn count_all_visible(map: &HeightMap) -> i32 {
+    let mut count = 0;
+
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            if tree_visible(map, x, y) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+// calculate the scenic score of a given tree
+fn scenic_score(map: &HeightMap, x: usize, y: usize) -> i32 {
+    let directions = tree_lines(map, x, y);
+    let target = map[(x, y)];
+    let mut product = 1;
+
+    for direction in directions {
+        let mut view_distance = 0;
+
+        // get the height of the first tree that blocks view (equal or taller than target)
+        for tree in direction.iter() {
+            view_distance += 1;
+            if *tree >= target {
+                break;
+            }
+        }
+
+        // multiply each directions view distance
+        product *= view_distance;
+    }
+
+    product
+}
+
+// calculate scenic score for every tree and find highest
+fn highest_scenic_score(map: &HeightMap) -> Option<i32> {
+    let mut highest = None;
+
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            let score = scenic_score(map, x, y);
+            if highest.is_none() || score > highest.unwrap() {
+                highest = Some(score);
+            }
+        }
+    }
+
+    highest
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    type Input = HeightMap;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_map(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<i32> {
+        Ok(count_all_visible(map))
+    }
+
+    fn part2(map: &Self::Input) -> Result<i32> {
+        Ok(highest_scenic_score(map).expect("map should not be empty"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aocutils::examples!(Day08);
+    
+
+    static EXAMPLE_INPUT: &str = "30373\n25512\n65332\n33549\n35390";
+
+    fn example_map() -> HeightMap {
+        parse_map(EXAMPLE_INPUT).unwrap()
+    }
+
+    #[test]
+    fn parse_map_example() {
+        assert_eq!(example_map().rows().collect::<Vec<_>>(), vec![
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0]
+        ]);
+        assert!(parse_map("303\n2x5").is_err());
+    }
+
+    #[test]
+    fn tree_lines_example() {
+        assert_eq!(tree_lines(&example_map(), 2, 2), [
+            vec![5, 3],
+            vec![5, 3],
+            vec![5, 6],
+            vec![3, 2]
+        ]);
+    }
+
+    #[test]
+    fn tree_visible_example() {
+        assert!(tree_visible(&example_map(), 1, 1));
+    }
+
+    #[test]
+    fn count_all_visible_example() {
+        assert_eq!(count_all_visible(&example_map()), 21);
+    }
+
+    #[test]
+    fn scenic_score_example() {
+        assert_eq!(scenic_score(&example_map(), 2, 1), 4);
+        assert_eq!(scenic_score(&example_map(), 2, 3), 8);
+    }
+}
\ No newline at end of file
@@ -1,5 +1,5 @@
 use std::collections::HashSet;
-use std::fs;
+use aocutils::{Result, Solution, Unsolved};
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 struct Coordinate {
@@ -42,22 +42,38 @@ fn do_move(i: &mut HeadTail, instruction: &str) -> HashSet<Coordinate> {
     tail_history
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
+pub struct Day09;
 
-    let mut i = HeadTail {
-        head: Coordinate { x: 0, y: 0 },
-        tail: Coordinate { x: 0, y: 0 }
-    };
+impl Solution for Day09 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
 
-    let mut tail_histories: HashSet<Coordinate> = HashSet::new();
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = Unsolved;
 
-    for instruction in input.lines() {
-        let tail_history = do_move(&mut i, instruction);
-        tail_histories = tail_histories.union(&tail_history).map(|x| x.clone()).collect::<HashSet<_>>();
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
     }
 
-    println!("Part 1: {}", tail_histories.len());
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let mut i = HeadTail {
+            head: Coordinate { x: 0, y: 0 },
+            tail: Coordinate { x: 0, y: 0 }
+        };
+
+        let mut tail_histories: HashSet<Coordinate> = HashSet::new();
+
+        for instruction in input.lines() {
+            tail_histories.extend(do_move(&mut i, instruction));
+        }
+
+        Ok(tail_histories.len())
+    }
+
+    fn part2(_: &Self::Input) -> Result<Unsolved> {
+        Ok(Unsolved)
+    }
 }
 
 #[cfg(test)]
@@ -65,6 +81,8 @@ mod tests {
     use super::*;
     use assert_unordered::assert_eq_unordered;
 
+    aocutils::examples!(Day09, part1);
+
     static EXAMPLE_INPUT: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
 
     #[test]
@@ -90,12 +108,12 @@ mod tests {
 
         for instruction in EXAMPLE_INPUT.lines() {
             let tail_history = do_move(&mut i, instruction);
-            tail_histories = tail_histories.union(&tail_history).map(|x| x.clone()).collect::<HashSet<_>>();
+            tail_histories = tail_histories.union(&tail_history).cloned().collect::<HashSet<_>>();
         }
 
         assert_eq!(i.head, Coordinate { x: 2, y: 2 });
         assert_eq!(i.tail, Coordinate { x: 1, y: 2 });
-        assert_eq_unordered!(tail_histories.iter().map(|x| x.clone()).collect::<Vec<_>>(), vec![
+        assert_eq_unordered!(tail_histories.iter().cloned().collect::<Vec<_>>(), vec![
             Coordinate { x: 0, y: 0 },
             Coordinate { x: 1, y: 0 },
             Coordinate { x: 2, y: 0 },
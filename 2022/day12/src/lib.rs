@@ -0,0 +1,132 @@
+use aocutils::{bfs, Error, Grid, Result, Solution};
+
+type Coords = (usize, usize);
+
+pub struct HeightMap {
+    heights: Grid<u8>,
+    start: Coords,
+    end: Coords
+}
+
+fn convert_height(height: char) -> u8 {
+    match height {
+        'S' => b'a',
+        'E' => b'z',
+        _ => height as u8
+    }
+}
+
+fn convert_map(map: &str) -> Result<HeightMap> {
+    let grid = Grid::try_parse(map, |c| (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c))?;
+    let find = |target| grid.find(|&c| c == target).ok_or_else(|| Error::invalid_state(format!("the map has no {}", target)));
+
+    Ok(HeightMap { start: find('S')?, end: find('E')?, heights: grid.map(|&c| convert_height(c)) })
+}
+
+// the squares next to `pos` that can be climbed to, which are at most one higher
+fn climbable(heights: &Grid<u8>, pos: Coords) -> impl Iterator<Item = Coords> + '_ {
+    heights.neighbours4(pos).filter(move |&next| heights[next] <= heights[pos] + 1)
+}
+
+// the squares next to `pos` that could have been climbed from
+fn descendable(heights: &Grid<u8>, pos: Coords) -> impl Iterator<Item = Coords> + '_ {
+    heights.neighbours4(pos).filter(move |&prev| heights[pos] <= heights[prev] + 1)
+}
+
+fn best_path_len(map: &HeightMap) -> Option<usize> {
+    bfs(map.start, |&pos| climbable(&map.heights, pos), |&pos| pos == map.end).map(|(_, steps)| steps)
+}
+
+// searching backwards from the end means the first 'a' found is the closest one
+fn best_scenic_len(map: &HeightMap) -> Option<usize> {
+    bfs(map.end, |&pos| descendable(&map.heights, pos), |&pos| map.heights[pos] == b'a').map(|(_, steps)| steps)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+
+    type Input = HeightMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        convert_map(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        best_path_len(map).ok_or_else(|| Error::invalid_state("there is no path to the end"))
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        best_scenic_len(map).ok_or_else(|| Error::invalid_state("there is no path from any lowest square"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_unordered::assert_eq_unordered;
+    use super::*;
+
+    // I made up this small one because it would take ages to manually write all the connections for
+    // the actual example
+    static SMALL_MAP: &str = "Sbc\nedd\nacb";
+    static EXAMPLE_MAP: &str = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+
+    #[test]
+    fn climbable_example_edges() {
+        let heights = Grid::parse(SMALL_MAP, convert_height).unwrap();
+        let actual = heights.iter()
+            .flat_map(|(pos, _)| climbable(&heights, pos).map(move |next| (pos, next)))
+            .collect::<Vec<_>>();
+
+        // squares are numbered left to right, top to bottom
+        let expected = [
+            (0, 1), (1, 0),
+            (1, 2), (2, 1),
+            (2, 5), (5, 2),
+            (3, 4), (4, 3), (3, 0),
+            (4, 5), (4, 7), (5, 4), (7, 4), (4, 1),
+            (7, 8), (8, 7),
+            (3, 6), (7, 6),
+            (5, 8)
+        ].map(|(a, b): (usize, usize)| ((a % 3, a / 3), (b % 3, b / 3)));
+
+        assert_eq_unordered!(actual, expected.to_vec(), "compare climbable edges");
+    }
+
+    #[test]
+    fn descendable_is_reverse_of_climbable() {
+        let map = convert_map(EXAMPLE_MAP).unwrap();
+
+        for (pos, _) in map.heights.iter() {
+            for next in climbable(&map.heights, pos) {
+                assert!(descendable(&map.heights, next).any(|prev| prev == pos));
+            }
+        }
+    }
+
+    #[test]
+    fn convert_map_example_start_end() {
+        let map = convert_map(EXAMPLE_MAP).unwrap();
+
+        assert_eq!(map.start, (0, 0));
+        assert_eq!(map.end, (5, 2));
+    }
+
+    #[test]
+    fn best_path_example() {
+        let map = convert_map(EXAMPLE_MAP).unwrap();
+
+        assert_eq!(best_path_len(&map), Some(31));
+    }
+
+    #[test]
+    fn best_scenic_example() {
+        let map = convert_map(EXAMPLE_MAP).unwrap();
+
+        assert_eq!(best_scenic_len(&map), Some(29));
+    }
+}
@@ -1,7 +1,7 @@
 use serde_json::{Value, json};
 use itertools::Itertools;
 use std::cmp::Ordering;
-use std::fs;
+use aocutils::{Result, Solution};
 
 fn parse_data(input: &str) -> Vec<(Value, Value)> {
     input.split("\n\n").map(|group| {
@@ -75,8 +75,8 @@ fn compare_packets(pair: &(Value, Value)) -> Ordering {
     }
 }
 
-fn get_decoder_keys(packets: &Vec<Value>) -> (usize, usize) {
-    let mut packets = packets.clone();
+fn get_decoder_keys(packets: &[Value]) -> (usize, usize) {
+    let mut packets = packets.to_vec();
 
     let (d1, d2) = (json!([[2]]), json!([[6]]));
 
@@ -93,20 +93,32 @@ fn get_decoder_keys(packets: &Vec<Value>) -> (usize, usize) {
     (d1_pos + 1, d2_pos + 1)
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let parsed = parse_data(&input);
+pub struct Day13;
 
-    let part1 = parsed.iter()
-        .enumerate() // number each item
-        .filter(|(_, p)| compare_packets(p) != Ordering::Greater) // filter out items that are
-        .map(|(i, _)| i+1)
-        .sum::<usize>();
+impl Solution for Day13 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
 
-    println!("Part 1: {}", part1);
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let part2 = get_decoder_keys(&parse_data_flat(&input));
-    println!("Part 2: {}", part2.0 * part2.1);
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(parse_data(input).iter()
+            .enumerate() // number each item
+            .filter(|(_, p)| compare_packets(p) != Ordering::Greater) // filter out items that are
+            .map(|(i, _)| i+1)
+            .sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let (a, b) = get_decoder_keys(&parse_data_flat(input));
+        Ok(a * b)
+    }
 }
 
 #[cfg(test)]
@@ -120,7 +132,7 @@ mod tests {
         let parsed = parse_data(&input);
 
         assert_eq!(
-            parsed.iter().map(|p| compare_packets(p)).collect::<Vec<_>>(),
+            parsed.iter().map(compare_packets).collect::<Vec<_>>(),
             vec![Ordering::Less, Ordering::Less, Ordering::Greater, Ordering::Less, Ordering::Greater, Ordering::Less, Ordering::Greater, Ordering::Greater]);
     }
 
@@ -2,14 +2,18 @@
 // for stone in the drop function to save memory space. (e.g. does the coordinate below intersect
 // with any of the lines in the puzzle input)
 
-use std::{collections::HashMap, error::Error, fs};
+use std::{collections::HashMap, error::Error};
 use itertools::Itertools;
+use aocutils::{Result, Solution, viz::{Animation, Color, Palette}};
 
 type Coordinate = (i32, i32);
 type ScanMap = HashMap<Coordinate, UnitType>;
 
+const SOURCE: Coordinate = (500, 0);
+const SAND_PER_FRAME: usize = 20;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum UnitType {
+pub enum UnitType {
     Rock,
     Sand
 }
@@ -30,7 +34,7 @@ fn parse_scan(input: &str) -> Result<(ScanMap, i32), Box<dyn Error>> {
 
     for line in input.lines() {
         // split by arrow to get all of the coordinates, then use windows to get each pair (e.g. a,b,c,d -> a+b, b+c, c+d)
-        let groups = line.split(" -> ").into_iter().tuple_windows::<(_, _)>();
+        let groups = line.split(" -> ").tuple_windows::<(_, _)>();
 
         for group in groups {
             let (mut x1, mut y1) = parse_coordinate(group.0)?;
@@ -72,7 +76,7 @@ fn parse_scan(input: &str) -> Result<(ScanMap, i32), Box<dyn Error>> {
 // outputs none if no more sand can be dropped, otherwise where the piece landed
 fn drop_sand(map: &mut ScanMap, source: &Coordinate, lowest: &i32, has_floor: bool) -> Option<Coordinate> {
     // initialise the new sand to be from the source
-    let (mut sand_x, mut sand_y) = source.clone();
+    let (mut sand_x, mut sand_y) = *source;
 
     loop {
         // if the sand is lower than the lowest point (i.e. fallen into the 'void' for part 1, or hit the floor for part 2)
@@ -86,20 +90,20 @@ fn drop_sand(map: &mut ScanMap, source: &Coordinate, lowest: &i32, has_floor: bo
         }
 
         // try moving down vertically
-        if map.get(&(sand_x, sand_y + 1)) == None {
+        if map.get(&(sand_x, sand_y + 1)).is_none() {
             sand_y += 1;
             continue;
         }
 
         // try moving down + left
-        if map.get(&(sand_x - 1, sand_y + 1)) == None {
+        if map.get(&(sand_x - 1, sand_y + 1)).is_none() {
             sand_x -= 1;
             sand_y += 1;
             continue;
         }
 
         // try moving down + right
-        if map.get(&(sand_x + 1, sand_y + 1)) == None {
+        if map.get(&(sand_x + 1, sand_y + 1)).is_none() {
             sand_x += 1;
             sand_y += 1;
             continue;
@@ -123,7 +127,7 @@ fn drop_sand(map: &mut ScanMap, source: &Coordinate, lowest: &i32, has_floor: bo
 fn drop_sand_loop(map: &mut ScanMap, source: &Coordinate, lowest: &i32, has_floor: bool) -> i32 {
     let mut count = 0;
 
-    while drop_sand(map, source, lowest, has_floor) != None {
+    while drop_sand(map, source, lowest, has_floor).is_some() {
         count += 1;
     }
 
@@ -135,21 +139,75 @@ fn drop_sand_loop(map: &mut ScanMap, source: &Coordinate, lowest: &i32, has_floo
     count
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let (map, lowest) = parse_scan(&input).expect("should be able to parse map");
-    let source = (500, 0);
+// draw the scan from the source down to the floor, wide enough to fit a full pile of sand
+fn draw_scan(map: &ScanMap, lowest: i32) -> String {
+    let floor = lowest + 2;
+
+    (0..=floor).map(|y| {
+        (SOURCE.0 - floor..=SOURCE.0 + floor).map(|x| {
+            match map.get(&(x, y)) {
+                _ if y == floor => '#',
+                Some(UnitType::Rock) => '#',
+                Some(UnitType::Sand) => 'o',
+                None if (x, y) == SOURCE => '+',
+                None => '.'
+            }
+        }).collect::<String>()
+    }).join("\n")
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
 
-    let mut map1 = map.clone();
-    println!("Part 1: {}", drop_sand_loop(&mut map1, &source, &lowest, false));
+    type Input = (ScanMap, i32);
+    type Part1 = i32;
+    type Part2 = i32;
 
-    let mut map2 = map.clone();
-    println!("Part 2: {}", drop_sand_loop(&mut map2, &source, &lowest, true));
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_scan(input).expect("should be able to parse map"))
+    }
+
+    fn part1((map, lowest): &Self::Input) -> Result<i32> {
+        Ok(drop_sand_loop(&mut map.clone(), &SOURCE, lowest, false))
+    }
+
+    fn part2((map, lowest): &Self::Input) -> Result<i32> {
+        Ok(drop_sand_loop(&mut map.clone(), &SOURCE, lowest, true))
+    }
+
+    // the sand from part 2 piling up until it covers the source
+    fn animate((map, lowest): &Self::Input) -> Option<Animation> {
+        let (mut map, lowest) = (map.clone(), *lowest);
+        let mut full = false;
+
+        let frames = std::iter::once(draw_scan(&map, lowest)).chain(std::iter::from_fn(move || {
+            if full {
+                return None;
+            }
+
+            for _ in 0..SAND_PER_FRAME {
+                if drop_sand(&mut map, &SOURCE, &lowest, true).is_none() {
+                    // drop_sand doesn't put down the last grain, the one that covers the source
+                    map.insert(SOURCE, UnitType::Sand);
+                    full = true;
+                    break;
+                }
+            }
+
+            Some(draw_scan(&map, lowest))
+        }));
+
+        Some(Animation::new(frames).fps(30.0).palette(Palette::new().with("#", Color::BrightBlack).with("o", Color::Yellow).with("+", Color::Red)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    
 
     static EXAMPLE_SCAN: &str = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
 
@@ -168,31 +226,31 @@ mod tests {
 
     #[test]
     fn parse_scan_lowest_example() {
-        let (_, lowest) = parse_scan(&EXAMPLE_SCAN).unwrap();
+        let (_, lowest) = parse_scan(EXAMPLE_SCAN).unwrap();
         assert_eq!(lowest, 9);
     }
 
     #[test]
     fn drop_sand_example() {
-        let (mut map, lowest) = parse_scan(&EXAMPLE_SCAN).unwrap();
+        let (mut map, lowest) = parse_scan(EXAMPLE_SCAN).unwrap();
         assert_eq!(drop_sand(&mut map, &(500, 0), &lowest, false), Some((500, 8)));
     }
 
     #[test]
     fn drop_sand_with_floor_example() {
-        let (mut map, lowest) = parse_scan(&EXAMPLE_SCAN).unwrap();
+        let (mut map, lowest) = parse_scan(EXAMPLE_SCAN).unwrap();
         assert_eq!(drop_sand(&mut map, &(490, 0), &lowest, true), Some((490, 10)));
     }
 
     #[test]
     fn drop_sand_loop_example() {
-        let (mut map, lowest) = parse_scan(&EXAMPLE_SCAN).unwrap();
+        let (mut map, lowest) = parse_scan(EXAMPLE_SCAN).unwrap();
         assert_eq!(drop_sand_loop(&mut map, &(500, 0), &lowest, false), 24);
     }
 
     #[test]
     fn drop_sand_with_floor_loop_example() {
-        let (mut map, lowest) = parse_scan(&EXAMPLE_SCAN).unwrap();
+        let (mut map, lowest) = parse_scan(EXAMPLE_SCAN).unwrap();
         assert_eq!(drop_sand_loop(&mut map, &(500, 0), &lowest, true), 93);
     }
 }
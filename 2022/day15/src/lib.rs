@@ -1,12 +1,12 @@
 // inspiration was taken from https://github.com/tobyink/advent-of-code/blob/main/2022/15/solution.rs
 
-use regex::Regex;
-use std::fs;
+use aocutils::{parse::FromInput, Result, Solution};
 
 type Coordinate = (i32, i32);
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-struct Sensor {
+#[derive(Debug, PartialEq, Eq, Clone, FromInput)]
+#[aoc(pattern = "Sensor at x={sensor.0}, y={sensor.1}: closest beacon is at x={beacon.0}, y={beacon.1}")]
+pub struct Sensor {
     sensor: Coordinate,
     beacon: Coordinate
 }
@@ -25,28 +25,11 @@ fn manhattan_dist(a: &Coordinate, b: &Coordinate) -> i32 {
     i32::abs(a.0 - b.0) + i32::abs(a.1 - b.1)
 }
 
-fn parse_input(input: &str) -> Vec<Sensor> {
-    let mut sensors = Vec::new();
-    let re = Regex::new(r"^Sensor at x=([-\d]+), y=([-\d]+): closest beacon is at x=([-\d]+), y=([-\d]+)$").unwrap();
-
-    for line in input.lines() {
-        let cap = re.captures(line).unwrap();
-
-        let sensor_x = cap.get(1).unwrap().as_str().parse::<i32>().unwrap();
-        let sensor_y = cap.get(2).unwrap().as_str().parse::<i32>().unwrap();
-        let beacon_x = cap.get(3).unwrap().as_str().parse::<i32>().unwrap();
-        let beacon_y = cap.get(4).unwrap().as_str().parse::<i32>().unwrap();
-
-        sensors.push(Sensor {
-            sensor: (sensor_x, sensor_y),
-            beacon: (beacon_x, beacon_y)
-        })
-    }
-
-    sensors
+fn parse_input(input: &str) -> Result<Vec<Sensor>> {
+    Sensor::from_input(input)
 }
 
-fn calculate_where_not_row(sensors: &Vec<Sensor>, x_min: i32, x_max: i32, y: i32) -> i32 {
+fn calculate_where_not_row(sensors: &[Sensor], x_min: i32, x_max: i32, y: i32) -> i32 {
     let mut count = 0;
 
     for x in x_min..x_max {
@@ -62,7 +45,7 @@ fn calculate_where_not_row(sensors: &Vec<Sensor>, x_min: i32, x_max: i32, y: i32
     count
 }
 
-fn get_missing_beacon(sensors: &Vec<Sensor>, from: Coordinate, to: Coordinate) -> Option<Coordinate> {
+fn get_missing_beacon(sensors: &[Sensor], from: Coordinate, to: Coordinate) -> Option<Coordinate> {
     for sensor in sensors {
         let borders = (0..(sensor.sensor_beacon_distance() + 1)).flat_map(|s| vec![
             (sensor.sensor.0 + s, sensor.sensor.1 + s - sensor.sensor_beacon_distance() + 1),
@@ -89,15 +72,28 @@ fn get_missing_beacon(sensors: &Vec<Sensor>, from: Coordinate, to: Coordinate) -
     None
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let sensors = parse_input(&input);
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
 
-    println!("Part 1: {}", calculate_where_not_row(&sensors, -2000000, 6000000, 2000000));
+    type Input = Vec<Sensor>;
+    type Part1 = i32;
+    type Part2 = i64;
 
-    let missing_beacon = get_missing_beacon(&sensors, (0, 0), (4000000, 4000000)).unwrap();
-    let tuning_frequency = ((missing_beacon.0 as i64) * 4000000) + (missing_beacon.1 as i64);
-    println!("Part 2: {}", tuning_frequency);
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(sensors: &Self::Input) -> Result<i32> {
+        Ok(calculate_where_not_row(sensors, -2000000, 6000000, 2000000))
+    }
+
+    fn part2(sensors: &Self::Input) -> Result<i64> {
+        let missing_beacon = get_missing_beacon(sensors, (0, 0), (4000000, 4000000)).expect("should find the missing beacon");
+        Ok(((missing_beacon.0 as i64) * 4000000) + (missing_beacon.1 as i64))
+    }
 }
 
 #[cfg(test)]
@@ -109,7 +105,7 @@ mod tests {
     fn parse_input_example() {
         let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
 
-        assert_eq!(parse_input(&input.lines().take(4).collect::<Vec<&str>>().join("\n")), vec![
+        assert_eq!(parse_input(&input.lines().take(4).collect::<Vec<&str>>().join("\n")).unwrap(), vec![
             Sensor { sensor: (2, 18), beacon: (-2, 15) },
             Sensor { sensor: (9, 16), beacon: (10, 16) },
             Sensor { sensor: (13, 2), beacon: (15, 3) },
@@ -119,13 +115,13 @@ mod tests {
 
     #[test]
     fn calculate_where_not_row_example1() {
-        assert_eq!(calculate_where_not_row(&vec![Sensor { sensor: (8, 7), beacon: (2, 10) }], -20000, 60000, 10), 12);
+        assert_eq!(calculate_where_not_row(&[Sensor { sensor: (8, 7), beacon: (2, 10) }], -20000, 60000, 10), 12);
     }
 
     #[test]
     fn calculate_where_not_row_example() {
         let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
-        let sensors = parse_input(&input);
+        let sensors = parse_input(&input).unwrap();
 
         assert_eq!(calculate_where_not_row(&sensors, -20000, 60000, 10), 26);
     }
@@ -133,7 +129,7 @@ mod tests {
     #[test]
     fn get_missing_beacon_example() {
         let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
-        let sensors = parse_input(&input);
+        let sensors = parse_input(&input).unwrap();
 
         assert_eq!(get_missing_beacon(&sensors, (0, 0), (20, 20)), Some((14, 11)));
     }
@@ -0,0 +1,178 @@
+// only the valves with a flow rate are worth going to, so the map is boiled down to the distances
+// between those. there are few enough of them that every set of open valves fits in a bitmask, and
+// the best pressure for each set can be found by trying every order they could be opened in.
+
+use std::collections::HashMap;
+use aocutils::{parse::Span, Error, Result, Solution};
+
+const START: &str = "AA";
+
+// more than this and the table of every set of valves gets too big
+const MAX_VALVES: usize = 20;
+
+/// The valves with a flow rate, and how many minutes it takes to get between them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Valves {
+    flow_rates: Vec<u32>,
+    // between each of the valves, with the start as the last one
+    distances: Vec<Vec<u32>>
+}
+
+// parse `Valve <name> has flow rate=<rate>; tunnels lead to valves <name>, <name>`, where a single
+// tunnel is `tunnel leads to valve <name>`
+fn parse_valve(line: Span<'_>) -> Result<(&str, u32, Vec<&str>)> {
+    let (valve, tunnels) = line.split_once("; ")?;
+    let (name, flow_rate) = valve.tag("Valve ")?.split_once(" has flow rate=")?;
+    let tunnels = tunnels.tag("tunnels lead to valves ").or_else(|_| tunnels.tag("tunnel leads to valve "))?;
+
+    Ok((name.as_str(), flow_rate.number()?, tunnels.list(", ", |t| Ok(t.as_str()))?))
+}
+
+fn parse_input(input: &str) -> Result<Valves> {
+    let valves = Span::new(input).map_lines(parse_valve)?;
+    let index = valves.iter().enumerate().map(|(i, (name, _, _))| (*name, i)).collect::<HashMap<_, _>>();
+
+    // floyd-warshall over every valve, which are all a minute apart from their neighbours
+    let n = valves.len();
+    let mut all_distances = vec![vec![u32::MAX / 2; n]; n];
+
+    for (i, (_, _, tunnels)) in valves.iter().enumerate() {
+        all_distances[i][i] = 0;
+
+        for tunnel in tunnels {
+            let j = *index.get(tunnel).ok_or_else(|| Error::invalid_state(format!("there is no valve {}", tunnel)))?;
+            all_distances[i][j] = 1;
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                all_distances[i][j] = all_distances[i][j].min(all_distances[i][k] + all_distances[k][j]);
+            }
+        }
+    }
+
+    let start = *index.get(START).ok_or_else(|| Error::invalid_state(format!("there is no valve {}", START)))?;
+    let mut useful = (0..n).filter(|&i| valves[i].1 > 0).collect::<Vec<_>>();
+
+    if useful.len() > MAX_VALVES {
+        return Err(Error::invalid_state(format!("there are {} valves with a flow rate, the most this can handle is {}", useful.len(), MAX_VALVES)));
+    }
+
+    useful.push(start);
+
+    Ok(Valves {
+        flow_rates: useful[..useful.len() - 1].iter().map(|&i| valves[i].1).collect(),
+        distances: useful.iter().map(|&i| useful.iter().map(|&j| all_distances[i][j]).collect()).collect()
+    })
+}
+
+// try every order of opening valves from `at`, keeping the most pressure released for each set of
+// open valves
+fn explore(valves: &Valves, at: usize, time_left: u32, open: usize, released: u32, best: &mut [u32]) {
+    best[open] = best[open].max(released);
+
+    for (next, &flow_rate) in valves.flow_rates.iter().enumerate() {
+        let travel = valves.distances[at][next];
+
+        // it takes another minute to open the valve, after which it has to have some time left
+        if open & (1 << next) != 0 || travel + 1 >= time_left {
+            continue;
+        }
+
+        let remaining = time_left - travel - 1;
+        explore(valves, next, remaining, open | (1 << next), released + remaining * flow_rate, best);
+    }
+}
+
+// the most pressure that can be released in `time` minutes by opening each set of valves, or any
+// subset of them
+fn best_per_set(valves: &Valves, time: u32) -> Vec<u32> {
+    let n = valves.flow_rates.len();
+    let mut best = vec![0; 1 << n];
+    explore(valves, n, time, 0, 0, &mut best);
+
+    // anything a subset can do, so can the whole set, by just not opening the rest
+    for valve in 0..n {
+        for set in 0..best.len() {
+            if set & (1 << valve) != 0 {
+                best[set] = best[set].max(best[set ^ (1 << valve)]);
+            }
+        }
+    }
+
+    best
+}
+
+fn best_pressure(valves: &Valves, time: u32) -> u32 {
+    best_per_set(valves, time).last().copied().unwrap_or(0)
+}
+
+// the two of us never need to open the same valve, so split the valves between us every way
+fn best_pressure_with_elephant(valves: &Valves, time: u32) -> u32 {
+    let best = best_per_set(valves, time);
+    let all = best.len() - 1;
+
+    (0..best.len()).map(|mine| best[mine] + best[all ^ mine]).max().unwrap_or(0)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 16;
+
+    type Input = Valves;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(valves: &Self::Input) -> Result<u32> {
+        Ok(best_pressure(valves, 30))
+    }
+
+    fn part2(valves: &Self::Input) -> Result<u32> {
+        // it takes 4 minutes to teach the elephant
+        Ok(best_pressure_with_elephant(valves, 26))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::*;
+
+    fn example() -> Valves {
+        parse_input(&fs::read_to_string("inputs/example.txt").expect("should be able to read input")).unwrap()
+    }
+
+    #[test]
+    fn parse_input_example() {
+        let valves = example();
+
+        // BB, CC, DD, EE, HH and JJ, then AA
+        assert_eq!(valves.flow_rates, vec![13, 2, 20, 3, 22, 21]);
+        assert_eq!(valves.distances[6], vec![1, 2, 1, 2, 5, 2, 0]);
+        assert_eq!(valves.distances[4][5], 7);
+    }
+
+    #[test]
+    fn parse_input_bad_tunnel() {
+        assert!(matches!(parse_input("Valve AA has flow rate=0; tunnel leads to valve BB"), Err(Error::InvalidState(_))));
+        assert!(matches!(parse_input("Valve AA has flow rate=0; tunnels go to valves BB"), Err(Error::Parse { .. })));
+    }
+
+    #[test]
+    fn best_pressure_example() {
+        assert_eq!(best_pressure(&example(), 30), 1651);
+    }
+
+    #[test]
+    fn best_pressure_with_elephant_example() {
+        assert_eq!(best_pressure_with_elephant(&example(), 26), 1707);
+    }
+}
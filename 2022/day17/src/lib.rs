@@ -0,0 +1,438 @@
+use std::collections::HashMap;
+use aocutils::{Error, Result, Solution, viz::{Animation, Color, Palette}};
+
+type Coordinate = (usize, usize);
+
+const ROCKS: &str = include_str!("../inputs/rocks.txt");
+const ANIMATION_HEIGHT: usize = 40;
+
+fn get_bounds(shape: &[Coordinate]) -> (usize, usize) {
+    (
+        shape.iter().max_by_key(|c| c.0).unwrap().0 + 1,
+        shape.iter().max_by_key(|c| c.1).unwrap().1 + 1
+    )
+}
+
+fn parse_rocks(input: &str) -> Vec<Vec<Coordinate>> {
+    input.split("\n\n").map(|r| {
+        let lines = r.lines();
+        let y_max = r.lines().count();
+        let mut shape = Vec::new();
+
+        for (y, row) in lines.enumerate() {
+            for (x, char) in row.chars().enumerate() {
+                if char == '#' {
+                    shape.push((x, y_max - y - 1));
+                }
+            }
+        }
+
+        shape
+    }).collect()
+}
+
+fn find_neighbours(map: &[[bool; 7]], (x, y): Coordinate) -> [bool; 4] {
+    [
+        {
+            if y+1 >= map.len() {
+                false
+            } else {
+                map[y + 1][x]
+            }
+        },
+        {
+            if y < 1 {
+                true
+            } else if map.len() < y || map.is_empty() {
+                false
+            } else {
+                map[y - 1][x]
+            }
+        },
+        {
+            if x == 0 {
+                true
+            } else if map.len() < y+1 || map.is_empty() {
+                false
+            } else {
+                map[y][x-1]
+            }
+        },
+        {
+            if x >= 6 {
+                true
+            } else if map.len() < y+1 || map.is_empty() {
+                false
+            } else {
+                map[y][x+1]
+            }
+        }
+    ]
+}
+
+// drop a rock until it comes to rest, with `jet` the index of the next jet of gas in `jets`. returns
+// the row the bottom of the rock ended up in
+fn drop_rock(map: &mut Vec<[bool; 7]>, rock: &[Coordinate], jets: &[char], jet: &mut usize) -> usize {
+    let (rock_x_bound, rock_y_bound) = get_bounds(rock);
+    let (mut x, mut y) = (2, map.len() + 3);
+
+    'outer: loop {
+        let gas_dir = jets[*jet];
+        *jet = (*jet + 1) % jets.len();
+
+        let mut move_ok = true;
+
+        for r in rock {
+            let (rx, ry) = (x + r.0, y + r.1);
+            let neighbours = find_neighbours(map, (rx, ry));
+
+            if gas_dir == '>' && neighbours[3] {
+                move_ok = false;
+                break;
+            }
+
+            if gas_dir == '<' && neighbours[2] {
+                move_ok = false;
+                break;
+            }
+        }
+
+        match (move_ok, gas_dir) {
+            (true, '>') => {
+                if x < (7 - rock_x_bound) {
+                    x += 1;
+                }
+            },
+            (true, '<') => {
+                x = x.saturating_sub(1);
+            }
+            (_, _) => {}
+        }
+
+        // check each part of rock for something underneath
+        for r in rock {
+            let (rx, ry) = (x + r.0, y + r.1);
+
+            let neighbours = find_neighbours(map, (rx, ry));
+
+            if neighbours[1] {
+                break 'outer;
+            }
+        }
+
+        y -= 1;
+    }
+
+    for _ in 0..((y+rock_y_bound).saturating_sub(map.len())) {
+        map.push([false; 7]);
+    }
+
+    for r in rock {
+        let (rx, ry) = (x + r.0, y + r.1);
+        map[ry][rx] = true;
+    }
+
+    y
+}
+
+// draws the tower from the top down, padded with empty rows up to `height`
+fn draw_tower(map: &[[bool; 7]], height: usize) -> Vec<String> {
+    let mut rows = (0..height.max(map.len())).rev().map(|y| {
+        match map.get(y) {
+            Some(row) => format!("|{}|", row.iter().map(|&rock| if rock { '#' } else { '.' }).collect::<String>()),
+            None => "|       |".to_string()
+        }
+    }).collect::<Vec<_>>();
+
+    rows.push("+-------+".to_string());
+    rows
+}
+
+// how far down the tower to look at its surface. a rock could fall further than this down a well,
+// but then the surface on its own doesn't say what will happen and it isn't trusted to find a cycle
+const SURFACE_DEPTH: usize = 64;
+
+// the shape of the top of the tower that falling rocks can get to, as a bitmask for each of the top
+// `depth` rows of the cells that can be reached from above without going any deeper
+fn surface(map: &[[bool; 7]], depth: usize) -> Vec<u8> {
+    let depth = depth.min(map.len());
+    let row = |d: usize| &map[map.len() - 1 - d];
+    let mut reachable = vec![0_u8; depth];
+    let mut stack = (0..7).filter(|&x| depth > 0 && !row(0)[x]).map(|x| (x, 0)).collect::<Vec<_>>();
+
+    while let Some((x, d)) = stack.pop() {
+        if reachable[d] & (1 << x) != 0 {
+            continue;
+        }
+        reachable[d] |= 1 << x;
+
+        // rocks only ever move sideways or down
+        let next = [(x.wrapping_sub(1), d), (x + 1, d), (x, d + 1)];
+        stack.extend(next.into_iter().filter(|&(x, d)| x < 7 && d < depth && !row(d)[x]));
+    }
+
+    reachable
+}
+
+fn drop_many(rocks: &[Vec<Coordinate>], jet_pattern: &str, n: i64) -> i64 {
+    drop_many_looking(rocks, jet_pattern, n, SURFACE_DEPTH)
+}
+
+// drop `n` rocks, skipping ahead once the tower starts repeating, judging by the top `surface_depth`
+// rows of it
+fn drop_many_looking(rocks: &[Vec<Coordinate>], jet_pattern: &str, n: i64, surface_depth: usize) -> i64 {
+    let jets = jet_pattern.chars().collect::<Vec<_>>();
+    let mut jet = 0;
+    let mut map = Vec::new();
+
+    // the number of rocks dropped and the height of the tower, the last time each state was seen
+    let mut seen: HashMap<(usize, usize, Vec<u8>), (i64, i64)> = HashMap::new();
+    // how far below the top of the tower each rock tried to go, which is the row under where it
+    // came to rest
+    let mut depths = Vec::new();
+    // the height of the whole cycles skipped over, once a repeat has been found
+    let mut skipped_height = None;
+    let mut i = 0;
+
+    while i < n {
+        let rock = (i % rocks.len() as i64) as usize;
+        let top = map.len();
+        let bottom = drop_rock(&mut map, &rocks[rock], &jets, &mut jet);
+        i += 1;
+
+        if skipped_height.is_some() {
+            continue;
+        }
+        depths.push(top.saturating_sub(bottom));
+
+        // the next rock, the next jet and the surface they land on decide everything that happens
+        // from here, as long as none of the rocks go below the part of the surface that's known, so
+        // once they repeat the tower just grows by the same amount every cycle
+        let state = ((rock + 1) % rocks.len(), jet, surface(&map, surface_depth));
+        let height = map.len() as i64;
+
+        match seen.insert(state, (i, height)) {
+            Some((first_i, first_height)) if depths[first_i as usize..].iter().all(|&d| d < surface_depth) => {
+                let cycles = (n - i) / (i - first_i);
+                i += cycles * (i - first_i);
+                skipped_height = Some(cycles * (height - first_height));
+            }
+            _ => {}
+        }
+    }
+
+    map.len() as i64 + skipped_height.unwrap_or(0)
+}
+
+// the jets of gas pushing the rocks, which have to all be `<` or `>`
+fn parse_jets(input: &str) -> Result<String> {
+    let jets = input.trim();
+
+    if let Some((i, c)) = jets.char_indices().find(|&(_, c)| c != '<' && c != '>') {
+        return Err(Error::parse_at(input, &jets[i..i + c.len_utf8()], "expected a jet of gas, < or >"));
+    }
+    if jets.is_empty() {
+        return Err(Error::invalid_state("there are no jets of gas"));
+    }
+
+    Ok(jets.to_string())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 17;
+
+    type Input = String;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_jets(input)
+    }
+
+    fn part1(jet_pattern: &Self::Input) -> Result<i64> {
+        Ok(drop_many(&parse_rocks(ROCKS), jet_pattern, 2022))
+    }
+
+    fn part2(jet_pattern: &Self::Input) -> Result<i64> {
+        Ok(drop_many(&parse_rocks(ROCKS), jet_pattern, 1000000000000))
+    }
+
+    fn animate(jet_pattern: &Self::Input) -> Option<Animation> {
+        let rocks = parse_rocks(ROCKS);
+        let jets = jet_pattern.chars().collect::<Vec<_>>();
+        let mut jet = 0;
+        let mut map = Vec::new();
+
+        // the top of the tower after each of the rocks from part 1 lands, always the same height so
+        // that it can be saved as a GIF
+        let frames = (0..2022).map(move |i| {
+            drop_rock(&mut map, &rocks[i % rocks.len()], &jets, &mut jet);
+            draw_tower(&map, ANIMATION_HEIGHT).into_iter().take(ANIMATION_HEIGHT + 1).collect::<Vec<_>>().join("\n")
+        });
+
+        Some(Animation::new(frames).fps(30.0).palette(Palette::new().with("#", Color::Yellow).with("|+-", Color::BrightBlack)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn print_maps(a: &[[bool; 7]], b: &[[bool; 7]]) {
+        let height = a.len().max(b.len());
+
+        for (a, b) in draw_tower(a, height).iter().zip(draw_tower(b, height)) {
+            println!("{}  {}", a, b);
+        }
+    }
+
+    #[test]
+    fn parse_jets_errors() {
+        assert_eq!(parse_jets(">><\n").unwrap(), ">><");
+        assert!(matches!(parse_jets("<<v>"), Err(Error::Parse { line: 1, column: 3, .. })));
+        assert!(matches!(parse_jets("\n"), Err(Error::InvalidState(_))));
+    }
+
+    #[test]
+    fn parse_rocks_example() {
+        let rocks = fs::read_to_string("inputs/rocks.txt").expect("should be able to read input");
+
+        assert_eq!(parse_rocks(&rocks), vec![
+            vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            vec![(1, 2), (0, 1), (1, 1), (2, 1), (1, 0)],
+            vec![(2, 2), (2, 1), (0, 0), (1, 0), (2, 0)],
+            vec![(0, 3), (0, 2), (0, 1), (0, 0)],
+            vec![(0, 1), (1, 1), (0, 0), (1, 0)]
+        ]);
+    }
+
+    #[test]
+    fn drop_rocks_example() {
+        let jets = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>".chars().collect::<Vec<_>>();
+        let mut jet = 0;
+        let mut map: Vec<[bool; 7]> = Vec::new();
+
+        drop_rock(&mut map, &[(0, 0), (1, 0), (2, 0), (3, 0)], &jets, &mut jet);
+        let expected1 = vec![
+            [false, false, true, true, true, true, false]
+        ];
+        print_maps(&map, &expected1);
+        assert_eq!(map, expected1, "first drop");
+
+        println!("---");
+
+        drop_rock(&mut map, &[(1, 2), (0, 1), (1, 1), (2, 1), (1, 0)], &jets, &mut jet);
+        let expected2 = vec![
+            [false, false, true, true, true, true, false],
+            [false, false, false, true, false, false, false],
+            [false, false, true, true, true, false, false],
+            [false, false, false, true, false, false, false]
+        ];
+        print_maps(&map, &expected2);
+        assert_eq!(map, expected2, "second drop");
+
+        println!("---");
+
+        drop_rock(&mut map, &[(2, 2), (2, 1), (0, 0), (1, 0), (2, 0)], &jets, &mut jet);
+        let expected3 = vec![
+            [false, false, true, true, true, true, false],
+            [false, false, false, true, false, false, false],
+            [false, false, true, true, true, false, false],
+            [true, true, true, true, false, false, false],
+            [false, false, true, false, false, false, false],
+            [false, false, true, false, false, false, false]
+        ];
+        print_maps(&map, &expected3);
+        assert_eq!(map, expected3, "third drop");
+
+        println!("---");
+
+        drop_rock(&mut map, &[(0, 3), (0, 2), (0, 1), (0, 0)], &jets, &mut jet);
+        let expected4 = vec![
+            [false, false, true, true, true, true, false],
+            [false, false, false, true, false, false, false],
+            [false, false, true, true, true, false, false],
+            [true, true, true, true, true, false, false],
+            [false, false, true, false, true, false, false],
+            [false, false, true, false, true, false, false],
+            [false, false, false, false, true, false, false]
+        ];
+        print_maps(&map, &expected4);
+        assert_eq!(map, expected4, "fourth drop");
+    }
+
+    #[test]
+    fn drop_many_example() {
+        let rocks = parse_rocks(&fs::read_to_string("inputs/rocks.txt").expect("should be able to read input"));
+        let jet_pattern = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+        let height = drop_many(&rocks, jet_pattern, 2022);
+
+        assert_eq!(height, 3068);
+    }
+
+    #[test]
+    fn drop_many_example_long() {
+        let rocks = parse_rocks(&fs::read_to_string("inputs/rocks.txt").expect("should be able to read input"));
+        let jet_pattern = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+        let height = drop_many(&rocks, jet_pattern, 1000000000000);
+
+        assert_eq!(height, 1514285714288);
+    }
+
+    // the height after dropping every rock one at a time, without skipping any cycles
+    fn drop_all(rocks: &[Vec<Coordinate>], jet_pattern: &str, n: usize) -> i64 {
+        let jets = jet_pattern.chars().collect::<Vec<_>>();
+        let mut jet = 0;
+        let mut map = Vec::new();
+
+        for i in 0..n {
+            drop_rock(&mut map, &rocks[i % rocks.len()], &jets, &mut jet);
+        }
+
+        map.len() as i64
+    }
+
+    #[test]
+    fn drop_many_synthetic() {
+        let rocks = parse_rocks(&fs::read_to_string("inputs/rocks.txt").expect("should be able to read input"));
+
+        // always pushed against one wall, so the other side of the tower is never filled in and there
+        // is never a full line, along with a few uneven patterns
+        for jet_pattern in ["<", ">", "<>", "><<", ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>", "<<<<>><><>>>><<>><<<>>><<>>>>><"] {
+            for n in [1, 7, 2022, 3001, 5555] {
+                assert_eq!(drop_many(&rocks, jet_pattern, n as i64), drop_all(&rocks, jet_pattern, n), "{} rocks with {}", n, jet_pattern);
+            }
+        }
+    }
+
+    #[test]
+    fn drop_many_shallow_surface() {
+        let rocks = parse_rocks(&fs::read_to_string("inputs/rocks.txt").expect("should be able to read input"));
+
+        // looking at so little of the surface that rocks keep falling below it, those repeats can't
+        // be trusted and it has to carry on until it finds one where they don't
+        for jet_pattern in ["<", "<>><<<><><><", "<<<<><>><>><", "><>><<<>", ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>"] {
+            for surface_depth in [0, 1, 2, 4, 8] {
+                for n in [2022, 3001] {
+                    assert_eq!(drop_many_looking(&rocks, jet_pattern, n as i64, surface_depth), drop_all(&rocks, jet_pattern, n), "{} rocks with {} looking {} deep", n, jet_pattern, surface_depth);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn drop_many_synthetic_long() {
+        let rocks = parse_rocks(&fs::read_to_string("inputs/rocks.txt").expect("should be able to read input"));
+
+        // always pushed to the left, every five rocks add the same to the height from the start
+        let cycle = drop_all(&rocks, "<", 5);
+        assert_eq!(drop_all(&rocks, "<", 5000), 1000 * cycle);
+        assert_eq!(drop_many(&rocks, "<", 1000000000000), 200000000000 * cycle);
+
+        assert_eq!(drop_many(&rocks, "<", 1000000000003), 200000000000 * cycle + drop_all(&rocks, "<", 3));
+    }
+}
@@ -0,0 +1,224 @@
+use std::{collections::HashSet, fmt};
+use aocutils::{parse::Span, Result, Solution};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point { x: i32, y: i32, z: i32 }
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+// parse `<x>,<y>,<z>`
+fn parse_point(line: Span<'_>) -> Result<Point> {
+    let (x, yz) = line.split_once(",")?;
+    let (y, z) = yz.split_once(",")?;
+
+    Ok(Point { x: x.number()?, y: y.number()?, z: z.number()? })
+}
+
+fn parse_input(input: &str) -> Result<HashSet<Point>> {
+    Ok(Span::new(input).map_lines(parse_point)?.into_iter().collect())
+}
+
+fn neighbours(point: &Point) -> [Point; 6] {
+    let Point { x, y, z } = *point;
+
+    [
+        Point { x: x - 1, y, z }, Point { x: x + 1, y, z },
+        Point { x, y: y - 1, z }, Point { x, y: y + 1, z },
+        Point { x, y, z: z - 1 }, Point { x, y, z: z + 1 }
+    ]
+}
+
+fn get_surface_area(points: &HashSet<Point>) -> i32 {
+    points.iter().map(|point| neighbours(point).iter().filter(|p| !points.contains(p)).count() as i32).sum()
+}
+
+// the corners of a box around the droplet with a layer of air all the way around it, so that the
+// outside air is all connected
+fn get_bounds(points: &HashSet<Point>) -> (Point, Point) {
+    let min = |f: fn(&Point) -> i32| points.iter().map(f).min().unwrap_or(0) - 1;
+    let max = |f: fn(&Point) -> i32| points.iter().map(f).max().unwrap_or(0) + 1;
+
+    (Point { x: min(|p| p.x), y: min(|p| p.y), z: min(|p| p.z) }, Point { x: max(|p| p.x), y: max(|p| p.y), z: max(|p| p.z) })
+}
+
+// all of the air connected to `start` without leaving the bounds
+fn flood_fill(points: &HashSet<Point>, (min, max): (Point, Point), start: Point) -> HashSet<Point> {
+    let in_bounds = |p: &Point| (min.x..=max.x).contains(&p.x) && (min.y..=max.y).contains(&p.y) && (min.z..=max.z).contains(&p.z);
+    let mut air = HashSet::from([start]);
+    let mut stack = vec![start];
+
+    while let Some(point) = stack.pop() {
+        for next in neighbours(&point) {
+            if in_bounds(&next) && !points.contains(&next) && air.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    air
+}
+
+// only the faces touching the air outside the droplet, not any trapped inside it
+fn get_exterior_surface_area(points: &HashSet<Point>) -> i32 {
+    let bounds = get_bounds(points);
+    let outside = flood_fill(points, bounds, bounds.0);
+
+    points.iter().map(|point| neighbours(point).iter().filter(|p| outside.contains(p)).count() as i32).sum()
+}
+
+/// A pocket of air trapped inside the droplet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AirPocket {
+    /// The lowest point in the pocket, to find it by
+    pub corner: Point,
+    pub volume: usize
+}
+
+impl fmt::Display for AirPocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} cube{} of air at {}", self.volume, if self.volume == 1 { "" } else { "s" }, self.corner)
+    }
+}
+
+/// Every pocket of air that can't be reached from outside the droplet, in order of where they are
+pub fn air_pockets(points: &HashSet<Point>) -> Vec<AirPocket> {
+    let bounds @ (min, max) = get_bounds(points);
+    let mut seen = flood_fill(points, bounds, min);
+    let mut pockets = Vec::new();
+
+    for x in min.x..=max.x {
+        for y in min.y..=max.y {
+            for z in min.z..=max.z {
+                let point = Point { x, y, z };
+                if points.contains(&point) || seen.contains(&point) {
+                    continue;
+                }
+
+                let pocket = flood_fill(points, bounds, point);
+                pockets.push(AirPocket { corner: *pocket.iter().min().expect("should contain the start"), volume: pocket.len() });
+                seen.extend(pocket);
+            }
+        }
+    }
+
+    pockets.sort_by_key(|p| p.corner);
+    pockets
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 18;
+
+    type Input = HashSet<Point>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(points: &Self::Input) -> Result<i32> {
+        Ok(get_surface_area(points))
+    }
+
+    fn part2(points: &Self::Input) -> Result<i32> {
+        Ok(get_exterior_surface_area(points))
+    }
+
+    fn explain(points: &Self::Input) -> Result<Option<String>> {
+        let pockets = air_pockets(points);
+        let mut explanation = format!("{} air pocket{} inside the droplet\n", pockets.len(), if pockets.len() == 1 { "" } else { "s" });
+
+        for pocket in &pockets {
+            explanation += &format!("  {}\n", pocket);
+        }
+
+        Ok(Some(explanation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aocutils::Error;
+    use super::*;
+
+    #[test]
+    fn parse_input_example() {
+        assert_eq!(parse_input("1,1,1\n2,1,1").unwrap(), HashSet::from([
+            Point { x: 1, y: 1, z: 1 },
+            Point { x: 2, y: 1, z: 1 }
+        ]));
+    }
+
+    #[test]
+    fn parse_input_errors() {
+        assert!(matches!(parse_input("1,1,1\n2,1"), Err(Error::Parse { line: 2, .. })));
+        assert!(matches!(parse_input("1,x,1"), Err(Error::Parse { line: 1, column: 3, .. })));
+    }
+
+    #[test]
+    fn get_surface_area_small_example() {
+        assert_eq!(get_surface_area(&HashSet::from([
+            Point { x: 1, y: 1, z: 1 },
+            Point { x: 2, y: 1, z: 1 }
+        ])), 10);
+    }
+
+    #[test]
+    fn get_surface_area_example() {
+        let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
+        let points = parse_input(&input).unwrap();
+        assert_eq!(get_surface_area(&points), 64);
+    }
+
+    #[test]
+    fn get_exterior_surface_area_example() {
+        let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
+        let points = parse_input(&input).unwrap();
+        assert_eq!(get_exterior_surface_area(&points), 58);
+    }
+
+    // a 3x3x3 cube with the middle and one corner missing
+    fn hollow_cube() -> HashSet<Point> {
+        (0..27).map(|i| Point { x: i % 3, y: i / 3 % 3, z: i / 9 }).filter(|p| *p != Point { x: 1, y: 1, z: 1 } && *p != Point { x: 0, y: 0, z: 0 }).collect()
+    }
+
+    #[test]
+    fn get_exterior_surface_area_hollow() {
+        let points = hollow_cube();
+
+        // the missing corner takes three faces off the outside and adds three going into it, and the
+        // missing middle only has faces on the inside
+        assert_eq!(get_exterior_surface_area(&points), 54);
+        assert_eq!(get_surface_area(&points), 60);
+        assert_eq!(air_pockets(&points), vec![AirPocket { corner: Point { x: 1, y: 1, z: 1 }, volume: 1 }]);
+    }
+
+    #[test]
+    fn air_pockets_example() {
+        let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
+
+        assert_eq!(air_pockets(&parse_input(&input).unwrap()), vec![AirPocket { corner: Point { x: 2, y: 2, z: 5 }, volume: 1 }]);
+    }
+
+    #[test]
+    fn air_pockets_separate() {
+        // a pocket of two cubes of air, another of one, and a hole in the side that isn't a pocket
+        let mut points = (0..60).map(|i| Point { x: i % 5, y: i / 5 % 3, z: i / 15 }).collect::<HashSet<_>>();
+        for air in [(1, 1, 1), (3, 1, 1), (1, 1, 2), (4, 1, 2)] {
+            points.remove(&Point { x: air.0, y: air.1, z: air.2 });
+        }
+
+        let pockets = air_pockets(&points);
+        assert_eq!(pockets.iter().map(|p| p.to_string()).collect::<Vec<_>>(), vec!["2 cubes of air at 1,1,1", "1 cube of air at 3,1,1"]);
+        assert_eq!(get_exterior_surface_area(&points), get_surface_area(&points) - 10 - 6);
+    }
+}
@@ -0,0 +1,226 @@
+// numbers can appear more than once, so they're tracked by where they started rather than by value.
+// the order is kept in a list of blocks, so that finding, removing and inserting a number only
+// means looking through one block and counting the lengths of the ones before it.
+
+use aocutils::{parse::Span, Error, Result, Solution};
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+struct Mixer<'a> {
+    numbers: &'a [i64],
+    // the original index of each number, in their current order
+    blocks: Vec<Vec<usize>>,
+    // which block each number is in, by original index
+    block_of: Vec<usize>,
+    block_size: usize
+}
+
+impl<'a> Mixer<'a> {
+    fn new(numbers: &'a [i64]) -> Self {
+        let block_size = (numbers.len() as f64).sqrt().ceil().max(1.0) as usize;
+        let mut mixer = Mixer { numbers, blocks: Vec::new(), block_of: vec![0; numbers.len()], block_size };
+        mixer.rebuild((0..numbers.len()).collect());
+        mixer
+    }
+
+    // split the order back up into evenly sized blocks
+    fn rebuild(&mut self, order: Vec<usize>) {
+        self.blocks = order.chunks(self.block_size).map(|c| c.to_vec()).collect();
+
+        for (b, block) in self.blocks.iter().enumerate() {
+            for &i in block {
+                self.block_of[i] = b;
+            }
+        }
+    }
+
+    fn order(&self) -> Vec<usize> {
+        self.blocks.concat()
+    }
+
+    fn position(&self, i: usize) -> usize {
+        let b = self.block_of[i];
+        let before = self.blocks[..b].iter().map(|block| block.len()).sum::<usize>();
+
+        before + self.blocks[b].iter().position(|&j| j == i).expect("should be in its block")
+    }
+
+    fn insert(&mut self, position: usize, i: usize) {
+        let mut offset = position;
+        let mut b = 0;
+
+        while b < self.blocks.len() - 1 && offset > self.blocks[b].len() {
+            offset -= self.blocks[b].len();
+            b += 1;
+        }
+
+        self.blocks[b].insert(offset, i);
+        self.block_of[i] = b;
+
+        // the blocks only stay quick to search while none of them grow too big
+        if self.blocks[b].len() > 2 * self.block_size {
+            self.rebuild(self.order());
+        }
+    }
+
+    // move the number that started at index `i` forwards or backwards by its value, wrapping around.
+    // it isn't in the list while it moves, so a full lap is one less than the length
+    fn mix(&mut self, i: usize) {
+        let len = self.numbers.len();
+        if len < 2 {
+            return;
+        }
+
+        let position = self.position(i);
+        let b = self.block_of[i];
+        self.blocks[b].retain(|&j| j != i);
+
+        // moving to the very start is the same as moving to the end, which is where it goes
+        let new_position = match (position as i64 + self.numbers[i]).rem_euclid(len as i64 - 1) as usize {
+            0 => len - 1,
+            p => p
+        };
+
+        self.insert(new_position, i);
+    }
+
+    fn values(&self) -> Vec<i64> {
+        self.order().into_iter().map(|i| self.numbers[i]).collect()
+    }
+}
+
+// mix every number in the order they started in, `rounds` times over
+fn mix_all(input: &[i64], rounds: usize) -> Vec<i64> {
+    let mut mixer = Mixer::new(input);
+
+    for _ in 0..rounds {
+        for i in 0..input.len() {
+            mixer.mix(i);
+        }
+    }
+
+    mixer.values()
+}
+
+fn get_grove_coordinates(v: &[i64]) -> Result<[i64; 3]> {
+    let offset = v.iter().position(|&x| x == 0).ok_or_else(|| Error::invalid_state("there is no 0 in the file"))?;
+
+    Ok([1000, 2000, 3000].map(|n| v[(n + offset) % v.len()]))
+}
+
+fn parse_input(input: &str) -> Result<Vec<i64>> {
+    Span::new(input).map_lines(|l| l.number())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 20;
+
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i64> {
+        Ok(get_grove_coordinates(&mix_all(input, 1))?.iter().sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<i64> {
+        let decrypted = input.iter().map(|n| n * DECRYPTION_KEY).collect::<Vec<_>>();
+
+        Ok(get_grove_coordinates(&mix_all(&decrypted, 10))?.iter().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aocutils::examples!(Day20);
+
+    static EXAMPLE_INPUT: &str = "1\n2\n-3\n3\n-2\n0\n4";
+
+    #[test]
+    fn mix_example() {
+        let input = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(input, vec![1, 2, -3, 3, -2, 0, 4]);
+
+        let mut mixer = Mixer::new(&input);
+
+        mixer.mix(0);
+        assert_eq!(mixer.values(), vec![2, 1, -3, 3, -2, 0, 4], "mix element 1");
+
+        mixer.mix(1);
+        assert_eq!(mixer.values(), vec![1, -3, 2, 3, -2, 0, 4], "mix element 2");
+
+        mixer.mix(2);
+        assert_eq!(mixer.values(), vec![1, 2, 3, -2, -3, 0, 4], "mix element -3");
+
+        mixer.mix(3);
+        assert_eq!(mixer.values(), vec![1, 2, -2, -3, 0, 3, 4], "mix element 3");
+
+        mixer.mix(4);
+        assert_eq!(mixer.values(), vec![1, 2, -3, 0, 3, 4, -2], "mix element -2");
+
+        mixer.mix(5);
+        assert_eq!(mixer.values(), vec![1, 2, -3, 0, 3, 4, -2], "mix element 0");
+
+        mixer.mix(6);
+        assert_eq!(mixer.values(), vec![1, 2, -3, 4, 0, 3, -2], "mix element 4");
+    }
+
+    #[test]
+    fn grove_coordinates_example() {
+        let mixed = mix_all(&parse_input(EXAMPLE_INPUT).unwrap(), 1);
+        let grove = get_grove_coordinates(&mixed).unwrap();
+
+        assert_eq!(grove, [4, -3, 2]);
+    }
+
+    #[test]
+    fn decrypted_example() {
+        let input = parse_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(Day20::part2(&input).unwrap(), 1623178306);
+    }
+
+    // mixing one number at a time in a plain list, to check the blocks against
+    fn mix_slowly(input: &[i64], rounds: usize) -> Vec<i64> {
+        let mut order = (0..input.len()).collect::<Vec<_>>();
+
+        for _ in 0..rounds {
+            for i in 0..input.len() {
+                let position = order.iter().position(|&j| j == i).unwrap();
+                order.remove(position);
+
+                let new_position = (position as i64 + input[i]).rem_euclid(input.len() as i64 - 1) as usize;
+                order.insert(if new_position == 0 { input.len() - 1 } else { new_position }, i);
+            }
+        }
+
+        order.into_iter().map(|i| input[i]).collect()
+    }
+
+    #[test]
+    fn mix_duplicates() {
+        // plenty of repeated numbers, some moving many laps at once
+        let mut seed = 12345_i64;
+        let input = (0..500).map(|_| {
+            seed = (seed * 1103515245 + 12345) % (1 << 31);
+            (seed % 41 - 20) * if seed % 7 == 0 { DECRYPTION_KEY } else { 1 }
+        }).collect::<Vec<_>>();
+
+        assert_eq!(mix_all(&input, 3), mix_slowly(&input, 3));
+        assert_eq!(mix_all(&[0, 1, 1, -1, 1, 0], 2), mix_slowly(&[0, 1, 1, -1, 1, 0], 2));
+    }
+
+    #[test]
+    fn missing_zero() {
+        assert!(matches!(get_grove_coordinates(&mix_all(&[1, 2, 3], 1)), Err(Error::InvalidState(_))));
+    }
+}
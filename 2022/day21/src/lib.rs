@@ -0,0 +1,368 @@
+use std::{collections::{HashMap, HashSet}, fmt};
+use aocutils::{parse::Span, Error, Result, Solution};
+use num_rational::Ratio;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+// the numbers get big enough that multiplying two of them could overflow an i64 before reducing
+type Rational = Ratio<i128>;
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum Job {
+    Number(i64),
+    Add(String, String),
+    Subtract(String, String),
+    Multiply(String, String),
+    Divide(String, String)
+}
+
+// parse `<name>: <number>` or `<name>: <name> <op> <name>`
+fn parse_monkey(line: Span<'_>) -> Result<(String, Job)> {
+    let (name, job) = line.split_once(": ")?;
+
+    let job = match job.split_once(" ") {
+        Err(_) => Job::Number(job.number()?),
+        Ok((a, rest)) => {
+            let (op, b) = rest.split_once(" ")?;
+            let (a, b) = (a.as_str().to_string(), b.as_str().to_string());
+
+            match op.as_str() {
+                "+" => Job::Add(a, b),
+                "-" => Job::Subtract(a, b),
+                "*" => Job::Multiply(a, b),
+                "/" => Job::Divide(a, b),
+                _ => return Err(op.error("expected one of +, -, * or /"))
+            }
+        }
+    };
+
+    Ok((name.as_str().to_string(), job))
+}
+
+fn parse_input(input: &str) -> Result<HashMap<String, Job>> {
+    Ok(Span::new(input).map_lines(parse_monkey)?.into_iter().collect())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation { Add, Subtract, Multiply, Divide }
+
+impl Operation {
+    fn symbol(self) -> char {
+        match self {
+            Operation::Add => '+',
+            Operation::Subtract => '-',
+            Operation::Multiply => '*',
+            Operation::Divide => '/'
+        }
+    }
+
+    fn apply(self, a: Rational, b: Rational) -> Result<Rational> {
+        if self == Operation::Divide && b == Rational::from_integer(0) {
+            return Err(Error::invalid_state(format!("can't divide {} by zero", a)));
+        }
+
+        let result = match self {
+            Operation::Add => a.checked_add(&b),
+            Operation::Subtract => a.checked_sub(&b),
+            Operation::Multiply => a.checked_mul(&b),
+            Operation::Divide => a.checked_div(&b)
+        };
+
+        result.ok_or_else(|| Error::invalid_state(format!("{} {} {} is too big", a, self.symbol(), b)))
+    }
+}
+
+/// A monkey's job written out in terms of the one number that isn't known, if there is one, with
+/// every part that doesn't depend on it worked out already
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expression {
+    Number(Rational),
+    Unknown,
+    Operation(Box<Expression>, Operation, Box<Expression>)
+}
+
+impl Expression {
+    fn from_job(map: &HashMap<String, Job>, name: &str, unknown: Option<&str>) -> Result<Expression> {
+        Expression::from_job_waiting(map, name, unknown, &mut HashSet::new())
+    }
+
+    // `waiting` is every monkey between `name` and the one the expression is for, so a monkey that
+    // ends up waiting on itself is caught rather than recursing forever
+    fn from_job_waiting<'a>(map: &'a HashMap<String, Job>, name: &'a str, unknown: Option<&str>, waiting: &mut HashSet<&'a str>) -> Result<Expression> {
+        if unknown == Some(name) {
+            return Ok(Expression::Unknown);
+        }
+
+        let (a, op, b) = match map.get(name).ok_or_else(|| Error::invalid_state(format!("there is no monkey {}", name)))? {
+            Job::Number(n) => return Ok(Expression::Number(Rational::from_integer((*n).into()))),
+            Job::Add(a, b) => (a, Operation::Add, b),
+            Job::Subtract(a, b) => (a, Operation::Subtract, b),
+            Job::Multiply(a, b) => (a, Operation::Multiply, b),
+            Job::Divide(a, b) => (a, Operation::Divide, b)
+        };
+
+        if !waiting.insert(name) {
+            return Err(Error::invalid_state(format!("{} ends up waiting on itself", name)));
+        }
+
+        let (a, b) = (Expression::from_job_waiting(map, a, unknown, waiting)?, Expression::from_job_waiting(map, b, unknown, waiting)?);
+        waiting.remove(name);
+
+        Ok(match (a, b) {
+            (Expression::Number(a), Expression::Number(b)) => {
+                // monkeys only ever yell whole numbers
+                let n = op.apply(a, b)?;
+                if !n.is_integer() {
+                    return Err(Error::invalid_state(format!("{} would yell {} {} {} = {}, which isn't a whole number", name, a, op.symbol(), b, n)));
+                }
+                Expression::Number(n)
+            }
+            (a, b) => Expression::Operation(Box::new(a), op, Box::new(b))
+        })
+    }
+
+    // what the unknown has to be for this to come out as `target`, undoing each operation on the way
+    // down to it
+    fn solve(&self, target: Rational) -> Result<Rational> {
+        match self {
+            Expression::Unknown => Ok(target),
+            Expression::Number(n) => Err(Error::invalid_state(format!("{} doesn't depend on {}", n, HUMAN))),
+            Expression::Operation(a, op, b) => match (a.as_ref(), op, b.as_ref()) {
+                (a, Operation::Add, Expression::Number(n)) | (Expression::Number(n), Operation::Add, a) => a.solve(Operation::Subtract.apply(target, *n)?),
+                (a, Operation::Subtract, Expression::Number(n)) => a.solve(Operation::Add.apply(target, *n)?),
+                (Expression::Number(n), Operation::Subtract, b) => b.solve(Operation::Subtract.apply(*n, target)?),
+                (a, Operation::Multiply, Expression::Number(n)) | (Expression::Number(n), Operation::Multiply, a) => a.solve(Operation::Divide.apply(target, *n)?),
+                (a, Operation::Divide, Expression::Number(n)) => a.solve(Operation::Multiply.apply(target, *n)?),
+                (Expression::Number(n), Operation::Divide, b) => b.solve(Operation::Divide.apply(*n, target)?),
+                _ => Err(Error::invalid_state(format!("{} is used more than once in {}", HUMAN, self)))
+            }
+        }
+    }
+
+    // work it out for a given value of the unknown, with every division having to come out exact
+    fn evaluate(&self, value: Rational) -> Result<Rational> {
+        match self {
+            Expression::Unknown => Ok(value),
+            Expression::Number(n) => Ok(*n),
+            Expression::Operation(a, op, b) => {
+                let result = op.apply(a.evaluate(value)?, b.evaluate(value)?)?;
+                if !result.is_integer() {
+                    return Err(Error::invalid_state(format!("{} doesn't divide exactly when {} is {}", self, HUMAN, value)));
+                }
+                Ok(result)
+            }
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, outer: bool) -> fmt::Result {
+        match self {
+            Expression::Number(n) => write!(f, "{}", n),
+            Expression::Unknown => write!(f, "{}", HUMAN),
+            Expression::Operation(a, op, b) => {
+                if !outer { write!(f, "(")?; }
+                a.write(f, false)?;
+                write!(f, " {} ", op.symbol())?;
+                b.write(f, false)?;
+                if !outer { write!(f, ")")?; }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, true)
+    }
+}
+
+// what a monkey yells when everyone, including the human, does what the puzzle input says
+fn get_number(map: &HashMap<String, Job>, name: &str) -> Result<i64> {
+    match Expression::from_job(map, name, None)? {
+        Expression::Number(n) => n.to_integer().try_into().map_err(|_| Error::invalid_state(format!("{} yells {}, which is too big", name, n))),
+        e => Err(Error::invalid_state(format!("{} isn't a number", e)))
+    }
+}
+
+/// What `root` actually does, which is check that its two numbers are equal
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Equation { left: Expression, right: Expression }
+
+impl Equation {
+    fn from_root(map: &HashMap<String, Job>) -> Result<Equation> {
+        let (a, b) = match map.get(ROOT) {
+            Some(Job::Add(a, b) | Job::Subtract(a, b) | Job::Multiply(a, b) | Job::Divide(a, b)) => (a, b),
+            _ => return Err(Error::invalid_state(format!("{} should be waiting on two other monkeys", ROOT)))
+        };
+
+        Ok(Equation { left: Expression::from_job(map, a, Some(HUMAN))?, right: Expression::from_job(map, b, Some(HUMAN))? })
+    }
+
+    /// The value the unknown needs to be for both sides to be equal, which has to be a whole number
+    fn solve(&self) -> Result<i64> {
+        let value = match (&self.left, &self.right) {
+            (Expression::Number(n), side) | (side, Expression::Number(n)) => side.solve(*n)?,
+            _ => return Err(Error::invalid_state(format!("{} is on both sides of {}", HUMAN, self)))
+        };
+
+        if !value.is_integer() {
+            return Err(Error::invalid_state(format!("{} would have to be {}", HUMAN, value)));
+        }
+
+        // check the answer by putting it back in, which also makes sure every division is exact
+        if self.left.evaluate(value)? != self.right.evaluate(value)? {
+            return Err(Error::invalid_state(format!("{} = {} doesn't solve {}", HUMAN, value, self)));
+        }
+
+        value.to_integer().try_into().map_err(|_| Error::invalid_state(format!("{} = {} is too big", HUMAN, value)))
+    }
+}
+
+impl fmt::Display for Equation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.left, self.right)
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 21;
+
+    type Input = HashMap<String, Job>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<i64> {
+        get_number(monkeys, ROOT)
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<i64> {
+        Equation::from_root(monkeys)?.solve()
+    }
+
+    fn explain(monkeys: &Self::Input) -> Result<Option<String>> {
+        let equation = Equation::from_root(monkeys)?;
+
+        Ok(Some(match equation.solve() {
+            Ok(value) => format!("{}\n{} = {}", equation, HUMAN, value),
+            Err(e) => format!("{}\ncan't solve for {}: {}", equation, HUMAN, e)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::*;
+
+    fn monkeys(input: &str) -> HashMap<String, Job> {
+        parse_input(input).unwrap()
+    }
+
+    #[test]
+    fn parse_input_example() {
+        assert_eq!(monkeys("root: pppw + sjmn\ndbpl: 5\nptdq: humn - dvpt\nsjmn: drzm * dbpl\npppw: cczh / lfqf"), HashMap::from([
+            ("root".into(), Job::Add("pppw".into(), "sjmn".into())),
+            ("dbpl".into(), Job::Number(5)),
+            ("ptdq".into(), Job::Subtract("humn".into(), "dvpt".into())),
+            ("sjmn".into(), Job::Multiply("drzm".into(), "dbpl".into())),
+            ("pppw".into(), Job::Divide("cczh".into(), "lfqf".into()))
+        ]))
+    }
+
+    #[test]
+    fn parse_input_errors() {
+        assert!(matches!(parse_input("root: a % b"), Err(Error::Parse { line: 1, column: 9, .. })));
+        assert!(matches!(parse_input("root: a + b\na: five"), Err(Error::Parse { line: 2, .. })));
+        assert!(matches!(parse_input("root: a +"), Err(Error::Parse { .. })));
+    }
+
+    #[test]
+    fn get_number_example() {
+        let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
+        assert_eq!(get_number(&monkeys(&input), ROOT).unwrap(), 152);
+    }
+
+    #[test]
+    fn get_number_errors() {
+        assert!(matches!(get_number(&monkeys("root: a / b\na: 4\nb: 0"), ROOT), Err(Error::InvalidState(_))));
+        assert!(matches!(get_number(&monkeys("root: a / b\na: 5\nb: 2"), ROOT), Err(Error::InvalidState(_))));
+        assert!(matches!(get_number(&monkeys("root: a * a\na: 9223372036854775807"), ROOT), Err(Error::InvalidState(_))));
+        assert!(matches!(get_number(&monkeys("root: a * b\na: 2"), ROOT), Err(Error::InvalidState(_))));
+    }
+
+    #[test]
+    fn waiting_on_itself() {
+        let map = monkeys("root: a + b\na: c * b\nb: 3\nc: a - b");
+        assert!(matches!(get_number(&map, ROOT), Err(Error::InvalidState(_))));
+        assert!(matches!(Equation::from_root(&map), Err(Error::InvalidState(_))));
+        assert!(matches!(get_number(&monkeys("root: root + a\na: 1"), ROOT), Err(Error::InvalidState(_))));
+
+        // waiting on the same monkey twice is fine though
+        assert_eq!(get_number(&monkeys("root: a + a\na: b * b\nb: 3"), ROOT).unwrap(), 18);
+    }
+
+    fn example() -> Equation {
+        let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
+        Equation::from_root(&monkeys(&input)).unwrap()
+    }
+
+    #[test]
+    fn equation_example() {
+        assert_eq!(example().to_string(), "(4 + (2 * (humn - 3))) / 4 = 150");
+    }
+
+    #[test]
+    fn solve_example() {
+        assert_eq!(example().solve().unwrap(), 301);
+    }
+
+    #[test]
+    fn explain_example() {
+        let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
+
+        assert_eq!(Day21::explain(&monkeys(&input)).unwrap().unwrap(), "(4 + (2 * (humn - 3))) / 4 = 150\nhumn = 301");
+    }
+
+    #[test]
+    fn solve_every_operation() {
+        // the unknown is on the right of every operation
+        let map = monkeys("root: a - b\na: c - d\nb: 1\nc: 10\nd: f / g\nf: h * i\ng: 2\nh: 3\ni: k + j\nj: l / humn\nk: 1\nl: 20");
+        let equation = Equation::from_root(&map).unwrap();
+
+        assert_eq!(equation.to_string(), "10 - ((3 * (1 + (20 / humn))) / 2) = 1");
+        assert_eq!(equation.solve().unwrap(), 4);
+    }
+
+    #[test]
+    fn solve_inexact() {
+        // humn would have to be 5/2
+        let map = monkeys("root: a + b\na: humn * c\nb: 5\nc: 2");
+        assert!(matches!(Equation::from_root(&map).unwrap().solve(), Err(Error::InvalidState(_))));
+
+        // humn = 3 works out, but 3 / 2 isn't a whole number on the way there
+        let map = monkeys("root: a + b\na: d * c\nb: 3\nc: 2\nd: humn / c");
+        assert!(matches!(Equation::from_root(&map).unwrap().solve(), Err(Error::InvalidState(_))));
+    }
+
+    #[test]
+    fn solve_too_big() {
+        // the other side is about 2^126, so undoing humn / 4 overflows
+        let map = monkeys("root: a + b\na: humn / c\nb: d * d\nc: 4\nd: 9223372036854775807");
+        assert!(matches!(Equation::from_root(&map).unwrap().solve(), Err(Error::InvalidState(_))));
+    }
+
+    #[test]
+    fn solve_unknown_twice() {
+        let map = monkeys("root: a + b\na: humn * humn\nb: 4");
+        assert!(matches!(Equation::from_root(&map).unwrap().solve(), Err(Error::InvalidState(_))));
+    }
+}
@@ -1,12 +1,12 @@
-use std::fs;
+use aocutils::{Result, Solution};
 
 fn calibration_value(s: &str) -> i32 {
-    let digits = s.chars().filter(|c| c.is_digit(10));
-    format!("{}{}", digits.clone().next().unwrap(), digits.last().unwrap()).parse::<i32>().expect("should be able to parse digits")
+    let mut digits = s.chars().filter(|c| c.is_ascii_digit());
+    format!("{}{}", digits.clone().next().unwrap(), digits.next_back().unwrap()).parse::<i32>().expect("should be able to parse digits")
 }
 
 fn calibration_values_sum(s: &str) -> i32 {
-    s.lines().map(|l| calibration_value(l)).sum()
+    s.lines().map(calibration_value).sum()
 }
 
 fn calibration_values_sum_replaced_words(s: &str) -> i32 {
@@ -29,13 +29,27 @@ fn replace_words(s: &str) -> String {
         .replace("zero", "zero0zero")
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt")
-            .expect("should be able to read input");
-    
-    println!("Part 1: {}", calibration_values_sum(&input));
-    
-    println!("Part 2: {}", calibration_values_sum_replaced_words(&input));
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input = String;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32> {
+        Ok(calibration_values_sum(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<i32> {
+        Ok(calibration_values_sum_replaced_words(input))
+    }
 }
 
 #[cfg(test)]
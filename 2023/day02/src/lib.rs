@@ -1,5 +1,6 @@
-use std::{collections::HashMap, fs};
+use std::collections::HashMap;
 use regex::Regex;
+use aocutils::{Result, Solution};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 enum CubeColor {
@@ -9,7 +10,7 @@ enum CubeColor {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Game {
+pub struct Game {
     id: i32,
     sets: Vec<HashMap<CubeColor, i32>>
 }
@@ -42,14 +43,14 @@ fn parse_game(s: &str) -> Game {
     
     Game {
         id: captures.get(1).unwrap().as_str().parse::<i32>().unwrap(),
-        sets: sets
+        sets
     }
 }
 
 fn is_game_possible(g: &Game, maxes: &HashMap<CubeColor, i32>) -> bool {
     for set in g.sets.iter() {
         for (color, qty) in set {
-            let max = maxes.get(&color).unwrap_or(&0);
+            let max = maxes.get(color).unwrap_or(&0);
             if qty > max {
                 return false;
             }
@@ -73,28 +74,41 @@ fn minimum_cubes(g: &Game) -> HashMap<CubeColor, i32> {
     mins
 }
 
-fn part1(games: &Vec<Game>, maxes: &HashMap<CubeColor, i32>) -> i32 {
+fn part1(games: &[Game], maxes: &HashMap<CubeColor, i32>) -> i32 {
     games.iter().filter(|g| is_game_possible(g, maxes)).map(|g| g.id).sum()
 }
 
-fn part2(games: &Vec<Game>) -> i32 {
+fn part2(games: &[Game]) -> i32 {
     games.iter().map(|g| minimum_cubes(g).values().product::<i32>()).sum()
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let games = input.lines().map(|l| parse_game(l)).collect::<Vec<Game>>();
-    let part_1_maxes = HashMap::from([
-        (CubeColor::Red, 12),
-        (CubeColor::Green, 13),
-        (CubeColor::Blue, 14)
-    ]);
-    
-    let part1: i32 = part1(&games, &part_1_maxes);
-    println!("Part 1: {}", part1);
-    
-    let part2: i32 = part2(&games);
-    println!("Part 2: {}", part2);
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(parse_game).collect())
+    }
+
+    fn part1(games: &Self::Input) -> Result<i32> {
+        let part_1_maxes = HashMap::from([
+            (CubeColor::Red, 12),
+            (CubeColor::Green, 13),
+            (CubeColor::Blue, 14)
+        ]);
+
+        Ok(part1(games, &part_1_maxes))
+    }
+
+    fn part2(games: &Self::Input) -> Result<i32> {
+        Ok(part2(games))
+    }
 }
 
 #[cfg(test)]
@@ -131,7 +145,7 @@ mod tests {
     #[test]
     fn test_is_game_possible_example() {
         let example = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
-        let games = example.lines().map(|l| parse_game(l)).collect::<Vec<Game>>();
+        let games = example.lines().map(parse_game).collect::<Vec<Game>>();
         
         let part_1_maxes = HashMap::from([
             (CubeColor::Red, 12),
@@ -148,8 +162,8 @@ mod tests {
     #[test]
     fn test_part_2_example() {
         let example = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
-        let games = example.lines().map(|l| parse_game(l)).collect::<Vec<Game>>();
+        let games = example.lines().map(parse_game).collect::<Vec<Game>>();
         
         assert_eq!(part2(&games), 2286);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,197 @@
+//use colored::Colorize;
+use std::collections::HashSet;
+use aocutils::{Grid, Result, Solution};
+
+/// Start and end coordinates of a part number on the schematic
+type PartNumberLocation = ((usize, usize), (usize, usize));
+
+/// Convert the puzzle input to a grid of chars to make indexing eaiser
+fn convert_schematic(s: &str) -> Result<Grid<char>> {
+    Grid::parse(s, |c| c)
+}
+
+/// Simple function to check whether a given symbol on the schematic is one we care about for part 1 (not a number or dot)
+fn is_schematic_symbol(c: &char) -> bool {
+    !c.is_numeric() && c.ne(&'.')
+}
+
+/// Simple function to check whether a given symbol on the schematic is one we care about for part 2 (a cog / asterisk)
+fn is_gear_symbol(c: &char) -> bool {
+    c.eq(&'*')
+}
+
+/// With some chars and a position, search to the left and right of the position to find the start and end position of the number
+fn extract_part_number(line: &[char], pos: usize) -> (usize, usize) {
+    let mut start_pos = pos;
+    let mut end_pos = pos;
+    
+    // work backwards to start pos
+    for i in (0..pos).rev() {
+        if !line.get(i).unwrap().is_numeric() {
+            break
+        }
+        
+        start_pos = i;
+    }
+    
+    // work forwards to end pos
+    for i in pos..line.len() {
+        if !line.get(i).unwrap().is_numeric() {
+            break
+        }
+        
+        end_pos = i;
+    }
+
+    (start_pos, end_pos)
+}
+
+/// Flatten (because locations are in groups by the symbol they are by) and deduplicate locations for use with part 1
+fn flatten_dedup_part_number_locations(locations: Vec<Vec<PartNumberLocation>>) -> HashSet<PartNumberLocation> {
+    dedup_part_number_locations(locations.into_iter().flatten().collect::<Vec<PartNumberLocation>>())
+}
+
+/// Deduplicate locations for use with part 2
+fn dedup_part_number_locations(locations: Vec<PartNumberLocation>) -> HashSet<PartNumberLocation> {
+    HashSet::from_iter(locations)
+}
+
+/// Loop through all locations on the schematic, searching for valid symbols, then searching adjacent squares for part numbers
+fn find_part_numbers(schematic: &Grid<char>, symbol_selector: fn(&char) -> bool) -> Vec<Vec<PartNumberLocation>> {
+    let mut part_number_locations: Vec<Vec<PartNumberLocation>> = Vec::new();
+
+    for (pos, element) in schematic.iter() {
+        // skip this square if it's not a symbol we care about
+        if !symbol_selector(element) {
+            continue
+        }
+
+        // store locations for just this symbol, this allows them to be grouped by symbol for part 2
+        let mut symbol_locations: Vec<PartNumberLocation> = Vec::new();
+
+        // search adjacent squares for part numbers
+        for (search_x, search_y) in schematic.neighbours8(pos) {
+            // To improve this, any locations currently inside `symbol_locations` should be skipped so they don't have to be put into a set later on
+
+            if schematic[(search_x, search_y)].is_numeric() {
+                // extract part number
+                let (start_x, end_x) = extract_part_number(schematic.row(search_y), search_x);
+                symbol_locations.push(((start_x, search_y), (end_x, search_y)));
+            }
+        }
+
+        part_number_locations.push(symbol_locations);
+    }
+
+    part_number_locations
+}
+
+/// given a list of locations, extract the part numbers for each
+fn get_part_number_values(schematic: &Grid<char>, part_number_locations: &HashSet<PartNumberLocation>) -> Vec<i32> {
+    part_number_locations.iter().map(|((x1, y1), (x2, _))| {
+        schematic.row(*y1).iter().skip(*x1).take((x2+1)-x1).collect::<String>().parse::<i32>().unwrap()
+    }).collect()
+}
+
+// a useful debug function for printing out a schematic and highlighting where part numbers are found
+//fn pretty_print_schematic(schematic: &Grid<char>, part_number_locations: &HashSet<PartNumberLocation>) {
+//    for (y, elements) in schematic.rows().enumerate() {
+//        for (x, element) in elements.iter().enumerate() {
+//            let mut highlighted = false;
+//
+//            for ((x1, y1), (x2, y2)) in part_number_locations {
+//                if x >= *x1 && x <= *x2 && y >= *y1 && y <= *y2 {
+//                    highlighted = true;
+//                    break
+//                }
+//            }
+//
+//            if highlighted {
+//                print!("{}", element.to_string().on_green());
+//            } else {
+//                print!("{}", element);
+//            }
+//        }
+//
+//        println!("")
+//    }
+//}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input = Grid<char>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        convert_schematic(input)
+    }
+
+    fn part1(schematic: &Self::Input) -> Result<i32> {
+        let locations = find_part_numbers(schematic, is_schematic_symbol);
+        Ok(get_part_number_values(schematic, &flatten_dedup_part_number_locations(locations)).iter().sum())
+    }
+
+    fn part2(schematic: &Self::Input) -> Result<i32> {
+        Ok(find_part_numbers(schematic, is_gear_symbol).into_iter().map(|gear_number_locations| {
+            let dedup_locations = dedup_part_number_locations(gear_number_locations);
+            if dedup_locations.len() != 2 {
+                return 0;
+            }
+
+            let values = get_part_number_values(schematic, &dedup_locations);
+            values.iter().product::<i32>()
+        }).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    
+    #[test]
+    fn test_extract_part_number_example() {
+        let actual = extract_part_number(&".664.598..".chars().collect::<Vec<char>>(), 2);
+        let expected = (1, 3);
+        
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_example_sum_part_numbers() {
+        let example = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
+        let schematic = convert_schematic(&example).unwrap();
+        let locations = find_part_numbers(&schematic, is_schematic_symbol);
+        
+        let actual: i32 = get_part_number_values(&schematic, &flatten_dedup_part_number_locations(locations)).iter().sum();
+        let expected = 4361;
+        
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_example_gear_ratios() {
+        let example = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
+        let schematic = convert_schematic(&example).unwrap();
+        let locations = find_part_numbers(&schematic, is_gear_symbol);
+
+        let actual = locations.into_iter().map(|gear_number_locations| {
+            let dedup_locations = dedup_part_number_locations(gear_number_locations);
+            if dedup_locations.len() != 2 {
+                return 0;
+            }
+
+            let values = get_part_number_values(&schematic, &dedup_locations);
+            values.iter().product::<i32>()
+        }).sum::<i32>();
+        let expected = 467835;
+
+        assert_eq!(actual, expected);
+    }
+}
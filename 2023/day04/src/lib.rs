@@ -1,8 +1,8 @@
-use std::fs;
 use std::collections::HashMap;
+use aocutils::{Result, Solution};
 
-#[derive(Debug, PartialEq, Eq)]
-struct Scratchcard {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scratchcard {
     winning_numbers: Vec<i32>,
     numbers: Vec<i32>
 }
@@ -17,7 +17,7 @@ impl Scratchcard {
         if num_winning == 0 {
             0
         } else {
-            1 * i32::pow(2, num_winning as u32 - 1)
+            i32::pow(2, num_winning - 1)
         }
     }
 }
@@ -62,15 +62,27 @@ fn process_scratchcard_wins(cards: Vec<(i32, Scratchcard)>) -> Vec<i32> {
     inventory
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let cards = parse_scratchcards(&input);
-    
-    let part1: i32 = cards.iter().map(|c| c.1.score()).sum();
-    println!("Part 1: {}", part1);
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input = Vec<(i32, Scratchcard)>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_scratchcards(input))
+    }
 
-    let part2 = process_scratchcard_wins(cards).len();
-    println!("Part 2: {}", part2);
+    fn part1(cards: &Self::Input) -> Result<i32> {
+        Ok(cards.iter().map(|c| c.1.score()).sum())
+    }
+
+    fn part2(cards: &Self::Input) -> Result<usize> {
+        Ok(process_scratchcard_wins(cards.clone()).len())
+    }
 }
 
 #[cfg(test)]
@@ -83,7 +95,7 @@ mod tests {
     fn test_parse_scratchcards() {
         let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
         
-        let actual = parse_scratchcards(&input);
+        let actual = parse_scratchcards(input);
         let expected = vec![(1, Scratchcard { winning_numbers: vec![41, 48, 83, 86, 17], numbers: vec![83, 86, 6, 31, 17, 9, 48, 53] })];
         
         assert_eq!(actual, expected);
@@ -116,4 +128,4 @@ mod tests {
 
         assert_eq!(processed, vec![1, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 6])
     }
-}
\ No newline at end of file
+}
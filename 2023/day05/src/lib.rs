@@ -1,7 +1,8 @@
-use std::fs;
+use aocutils::{parse::{FromInput, Span}, Result, Solution};
 
-#[derive(Debug, PartialEq, Eq)]
-struct Mapping {
+#[derive(Debug, PartialEq, Eq, FromInput)]
+#[aoc(pattern = "{destination_range_start} {source_range_start} {range_length}")]
+pub struct Mapping {
     destination_range_start: u64,
     source_range_start: u64,
     range_length: u64
@@ -41,7 +42,7 @@ impl Mapping {
     }
 }
 
-fn is_location_possible(mapping_groups: &Vec<Vec<Mapping>>, seed_ranges: &Vec<(u64, u64)>, location: u64) -> bool {
+fn is_location_possible(mapping_groups: &[Vec<Mapping>], seed_ranges: &[(u64, u64)], location: u64) -> bool {
     let mut current = location;
 
     for group in mapping_groups.iter().rev() {
@@ -63,23 +64,32 @@ fn is_location_possible(mapping_groups: &Vec<Vec<Mapping>>, seed_ranges: &Vec<(u
     false
 }
 
-fn lowest_location(mapping_groups: Vec<Vec<Mapping>>, seed_ranges: &Vec<(u64, u64)>) -> Option<u64> {
-    let mut last_mappings: &mut Vec<Mapping> = mapping_groups.last().unwrap().clone();
-    last_mappings.sort_by_key(|x| x.destination_range().0);
+/// The seed to location mapping is made up of pieces that are each shifted by a constant amount, so the lowest location
+/// has to come from the start of a seed range or the start of one of those pieces
+fn lowest_location(mapping_groups: &[Vec<Mapping>], seed_ranges: &[(u64, u64)]) -> Option<u64> {
+    let mut candidates: Vec<u64> = seed_ranges.iter().map(|r| r.0).collect();
 
-    for mapping in last_mappings {
-        let (min, max) = mapping.destination_range();
-        for i in min..max {
-            if is_location_possible(&mapping_groups, seed_ranges, i) {
-                return Some(i);
+    for (i, group) in mapping_groups.iter().enumerate() {
+        for mapping in group {
+            let (min, max) = mapping.source_range();
+
+            for boundary in [min, max + 1] {
+                // walk the boundary back through the earlier groups to get the seed it came from
+                let seed = mapping_groups[..i].iter().rev().fold(boundary, |current, g| {
+                    g.iter().find_map(|m| m.map_backward(current)).unwrap_or(current)
+                });
+                candidates.push(seed);
             }
         }
     }
 
-    None
+    candidates.iter()
+        .map(|s| map_through(mapping_groups, s))
+        .filter(|l| is_location_possible(mapping_groups, seed_ranges, *l))
+        .min()
 }
 
-fn map_through(mapping_groups: &Vec<Vec<Mapping>>, seed: &u64) -> u64 {
+fn map_through(mapping_groups: &[Vec<Mapping>], seed: &u64) -> u64 {
     let mut seed = *seed;
     
     for group in mapping_groups {
@@ -94,36 +104,30 @@ fn map_through(mapping_groups: &Vec<Vec<Mapping>>, seed: &u64) -> u64 {
     seed
 }
 
-fn parse_mappings(s: &str) -> (Vec<u64>, Vec<Vec<Mapping>>) {
-    let mut seeds: Vec<u64> = Vec::new();
+fn parse_mappings(s: &str) -> Result<(Vec<u64>, Vec<Vec<Mapping>>)> {
+    let mut blocks = Span::new(s).blocks();
     let mut mapping_groups: Vec<Vec<Mapping>> = Vec::new();
 
-    // parse seeds
-    for x in s.lines().next().unwrap().split_once(": ").unwrap().1.split(" ") {
-        seeds.push(x.parse::<u64>().unwrap());
-    }
+    // parse seeds, there's always at least one block even if the input is empty
+    let (_, seeds) = blocks.next().unwrap().key_value(":")?;
+    let seeds = seeds.words(Span::number)?;
 
-    // parse groups    
-    for group in s.split("\n\n").skip(1) {
+    // parse groups, skipping the name of each group
+    for group in blocks {
         let mut mapping_group: Vec<Mapping> = Vec::new();
 
         for line in group.lines().skip(1) {
-            if line.trim() == "" {
+            if line.trim().is_empty() {
                 continue;
             }
 
-            let mut parts = line.split(" ");
-            mapping_group.push(Mapping {
-                destination_range_start: parts.next().unwrap().parse::<u64>().unwrap(),
-                source_range_start: parts.next().unwrap().parse::<u64>().unwrap(),
-                range_length: parts.next().unwrap().parse::<u64>().unwrap()
-            })
+            mapping_group.push(Mapping::from_span(line)?);
         }
         
         mapping_groups.push(mapping_group);
     }
     
-    (seeds, mapping_groups)
+    Ok((seeds, mapping_groups))
 }
 
 fn seeds_to_ranges(seeds: Vec<u64>) -> Vec<(u64, u64)> {
@@ -136,12 +140,27 @@ fn seeds_to_ranges(seeds: Vec<u64>) -> Vec<(u64, u64)> {
     ranges
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let (seeds, mapping_groups) = parse_mappings(&input);
-    
-    let part1 = seeds.iter().map(|s| map_through(&mapping_groups, s)).min().unwrap();
-    println!("Part 1: {}", part1);
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input = (Vec<u64>, Vec<Vec<Mapping>>);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_mappings(input)
+    }
+
+    fn part1((seeds, mapping_groups): &Self::Input) -> Result<u64> {
+        Ok(seeds.iter().map(|s| map_through(mapping_groups, s)).min().expect("should have at least one seed"))
+    }
+
+    fn part2((seeds, mapping_groups): &Self::Input) -> Result<u64> {
+        Ok(lowest_location(mapping_groups, &seeds_to_ranges(seeds.clone())).expect("should find a location"))
+    }
 }
 
 #[cfg(test)]
@@ -154,7 +173,7 @@ mod tests {
     fn test_parse_mappings() {
         let input = "seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-fertilizer map:\n0 15 37\n37 52 2\n39 0 15";
         
-        let actual = parse_mappings(input);
+        let actual = parse_mappings(input).unwrap();
         let expected = (vec![79, 14, 55, 13], vec![
             vec![
                 Mapping { destination_range_start: 50, source_range_start: 98, range_length: 2 },
@@ -183,7 +202,7 @@ mod tests {
     #[test]
     fn test_map_through_example() {
         let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
-        let (seeds, mapping_groups) = parse_mappings(&input);
+        let (seeds, mapping_groups) = parse_mappings(&input).unwrap();
         
         let actual = seeds.iter().map(|s| map_through(&mapping_groups, s)).collect::<Vec<u64>>();
         let expected = vec![82, 43, 86, 35];
@@ -199,7 +218,7 @@ mod tests {
     #[test]
     fn test_is_location_possible() {
         let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
-        let (seeds, mapping_groups) = parse_mappings(&input);
+        let (seeds, mapping_groups) = parse_mappings(&input).unwrap();
         let seed_ranges = seeds_to_ranges(seeds);
 
         assert!(is_location_possible(&mapping_groups, &seed_ranges, 46));
@@ -209,9 +228,9 @@ mod tests {
     #[test]
     fn test_lowest_location_example() {
         let input = fs::read_to_string("inputs/example.txt").expect("should be able to read input");
-        let (seeds, mapping_groups) = parse_mappings(&input);
+        let (seeds, mapping_groups) = parse_mappings(&input).unwrap();
         let seed_ranges = seeds_to_ranges(seeds);
 
         assert_eq!(lowest_location(&mapping_groups, &seed_ranges).unwrap(), 46);
     }
-}
\ No newline at end of file
+}
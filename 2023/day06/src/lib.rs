@@ -1,4 +1,4 @@
-use std::fs;
+use aocutils::{Result, Solution};
 
 #[derive(Debug, PartialEq, Eq)]
 struct Record {
@@ -29,7 +29,7 @@ impl Record {
 }
 
 fn parse_input(s: &str) -> Vec<Record> {
-    let times = s.lines().nth(0).unwrap();
+    let times = s.lines().next().unwrap();
     let distances = s.lines().nth(1).unwrap();
     
     times.split_whitespace().zip(distances.split_whitespace()).skip(1)
@@ -38,7 +38,7 @@ fn parse_input(s: &str) -> Vec<Record> {
 }
 
 fn parse_input_part2(s: &str) -> Record {
-    let (_, time) = s.lines().nth(0).unwrap().split_once(":").unwrap();
+    let (_, time) = s.lines().next().unwrap().split_once(":").unwrap();
     let (_, distance) = s.lines().nth(1).unwrap().split_once(":").unwrap();
     
     Record {
@@ -47,14 +47,27 @@ fn parse_input_part2(s: &str) -> Record {
     }
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-   
-    let part1: usize = parse_input(&input).iter().map(|r| r.ways_to_win().len()).product();
-    println!("Part 1: {}", part1);
-    
-    let part2 = parse_input_part2(&input).ways_to_win().len();
-    println!("Part 2: {}", part2);
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(parse_input(input).iter().map(|r| r.ways_to_win().len()).product())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(parse_input_part2(input).ways_to_win().len())
+    }
 }
 
 #[cfg(test)]
@@ -103,4 +116,4 @@ mod tests {
         
         assert_eq!(actual, expected);
     }
-}
\ No newline at end of file
+}
@@ -1,9 +1,10 @@
-use std::{cmp::Ordering, fs};
+use std::cmp::Ordering;
 
 use counter::Counter;
+use aocutils::{Result, Solution};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
-enum CardLabel {
+pub enum CardLabel {
     A,
     K,
     Q,
@@ -58,7 +59,7 @@ fn parse_hand(s: &str) -> (Vec<CardLabel>, u32) {
     )
 }
 
-fn get_hand_type(hand: &Vec<CardLabel>, part2: bool) -> HandType {
+fn get_hand_type(hand: &[CardLabel], part2: bool) -> HandType {
     if hand.len() != 5 {
         panic!("expected hand of length 5");
     }
@@ -73,8 +74,8 @@ fn get_hand_type(hand: &Vec<CardLabel>, part2: bool) -> HandType {
         }
     }
 
-    let counts1 = *counts.get(0).unwrap_or_else(|| &0);
-    let counts2 = *counts.get(1).unwrap_or_else(|| &0);
+    let counts1 = *counts.first().unwrap_or(&0);
+    let counts2 = *counts.get(1).unwrap_or(&0);
     let mut hand_type = HandType::HighCard;
 
     if counts1 >= 5 {
@@ -97,7 +98,7 @@ fn get_hand_type(hand: &Vec<CardLabel>, part2: bool) -> HandType {
     hand_type
 }
 
-fn compare_hands(a: &Vec<CardLabel>, b: &Vec<CardLabel>, part2: bool) -> Ordering {
+fn compare_hands(a: &[CardLabel], b: &[CardLabel], part2: bool) -> Ordering {
     let a_type = get_hand_type(a, part2) as usize;
     let b_type = get_hand_type(b, part2) as usize;
 
@@ -136,7 +137,7 @@ fn compare_hands(a: &Vec<CardLabel>, b: &Vec<CardLabel>, part2: bool) -> Orderin
         }
     }
 
-    return Ordering::Equal;
+    Ordering::Equal
 }
 
 fn total_winnings(values: Vec<(Vec<CardLabel>, u32)>, part2: bool) -> u32 {
@@ -146,21 +147,35 @@ fn total_winnings(values: Vec<(Vec<CardLabel>, u32)>, part2: bool) -> u32 {
     values.iter().enumerate().map(|(i, (_, bid_amount))| (i as u32 + 1) * bid_amount).sum()
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let values = input.lines().map(|l| parse_hand(l)).collect::<Vec<(Vec<CardLabel>, u32)>>();
+pub struct Day07;
 
-    let part1 = total_winnings(values.clone(), false);
-    println!("Part 1: {}", part1);
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
 
-    let part2 = total_winnings(values, true);
-    println!("Part 2: {}", part2);
+    type Input = Vec<(Vec<CardLabel>, u32)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(parse_hand).collect())
+    }
+
+    fn part1(values: &Self::Input) -> Result<u32> {
+        Ok(total_winnings(values.clone(), false))
+    }
+
+    fn part2(values: &Self::Input) -> Result<u32> {
+        Ok(total_winnings(values.clone(), true))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    aocutils::examples!(Day07);
+
     static EXAMPLE_INPUT: &str = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
 
     #[test]
@@ -207,7 +222,7 @@ mod tests {
 
     #[test]
     fn test_total_winnings() {
-        let input = EXAMPLE_INPUT.lines().map(|l| parse_hand(l)).collect::<Vec<(Vec<CardLabel>, u32)>>();
+        let input = EXAMPLE_INPUT.lines().map(parse_hand).collect::<Vec<(Vec<CardLabel>, u32)>>();
 
         assert_eq!(total_winnings(input, false), 6440);
     }
@@ -223,7 +238,7 @@ mod tests {
 
     #[test]
     fn test_total_winnings_part2() {
-        let input = EXAMPLE_INPUT.lines().map(|l| parse_hand(l)).collect::<Vec<(Vec<CardLabel>, u32)>>();
+        let input = EXAMPLE_INPUT.lines().map(parse_hand).collect::<Vec<(Vec<CardLabel>, u32)>>();
 
         assert_eq!(total_winnings(input, true), 5905);
     }
@@ -1,10 +1,11 @@
-use std::{collections::HashMap, fs};
+use std::collections::HashMap;
+use aocutils::{Result, Solution};
 
 static START_LOCATION: &str = "AAA";
 static END_LOCATION: &str = "ZZZ";
 
 #[derive(Debug, PartialEq, Eq)]
-struct Map {
+pub struct Map {
     directions: Vec<char>,
     nodes: HashMap<String, (String, String)>
 }
@@ -91,21 +92,35 @@ fn step_to_end_simultaneous(map: &Map) -> i64 {
     repeat_lcm
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input").expect("should be able to read input");
-    let map = parse_map(&input);
+pub struct Day08;
 
-    let part1 = step_to_end(&map, START_LOCATION, |n| n == END_LOCATION, 0).len() - 1;
-    println!("Part 1: {}", part1);
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
 
-    let part2 = step_to_end_simultaneous(&map);
-    println!("Part 2: {}", part2);
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_map(input))
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        Ok(step_to_end(map, START_LOCATION, |n| n == END_LOCATION, 0).len() - 1)
+    }
+
+    fn part2(map: &Self::Input) -> Result<i64> {
+        Ok(step_to_end_simultaneous(map))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    aocutils::examples!(Day08);
+
     static EXAMPLE_MAP1: &str = "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\nEEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)";
     static EXAMPLE_MAP2: &str = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
     static EXAMPLE_MAP3: &str = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)";
@@ -144,4 +159,11 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_step_to_end_simultaneous_example() {
+        let map = parse_map(EXAMPLE_MAP3);
+
+        assert_eq!(step_to_end_simultaneous(&map), 6);
+    }
+}
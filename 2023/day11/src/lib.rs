@@ -1,6 +1,6 @@
-use std::fs;
 
 use itertools::Itertools;
+use aocutils::{Result, Solution};
 
 type Map = Vec<Vec<char>>;
 type Coordinate = (u64, u64);
@@ -15,38 +15,21 @@ fn parse_map(s: &str) -> Map {
 
 /// Get the indexes of where the map expands (i.e. the indexes of empty rows and columns)
 fn get_map_expansions(map: &Map) -> (Vec<usize>, Vec<usize>) {
-    let y_max = map.len();
     let x_max = map[0].len();
 
     let mut y_expansions: Vec<usize> = Vec::new();
     let mut x_expansions: Vec<usize> = Vec::new();
 
     // expand in y direction
-    for y in 0..y_max {
-        let mut to_expand = true;
-        for x in 0..x_max {
-            if map[y][x] != '.' {
-                to_expand = false;
-                break;
-            }
-        }
-
-        if to_expand {
+    for (y, row) in map.iter().enumerate() {
+        if row.iter().all(|c| *c == '.') {
             y_expansions.push(y);
         }
     }
 
     // expand in x direction
     for x in 0..x_max {
-        let mut to_expand = true;
-        for y in 0..y_max {
-            if map[y][x] != '.' {
-                to_expand = false;
-                break;
-            }
-        }
-
-        if to_expand {
+        if map.iter().all(|row| row[x] == '.') {
             x_expansions.push(x);
         }
     }
@@ -113,7 +96,7 @@ fn manhattan_distance(x1: u64, y1: u64, x2: u64, y2: u64) -> u64 {
 }
 
 /// Calculate a list of distances for every point given
-fn get_distances(points: &Vec<Coordinate>) -> Vec<u64> {
+fn get_distances(points: &[Coordinate]) -> Vec<u64> {
     let mut distances = Vec::new();
 
     let pairs = points.iter().permutations(2).map(|p| {
@@ -140,21 +123,35 @@ fn all_distances(m: &Map, scale: u64) -> Vec<u64> {
     get_distances(&expanded_coordinates)
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let map = parse_map(&input);
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
 
-    let part1 = all_distances(&map, PART1_EXPANSION_SCALE).iter().sum::<u64>();
-    println!("Part 1: {}", part1);
+    type Input = Map;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    let part2 = all_distances(&map, PART2_EXPANSION_SCALE).iter().sum::<u64>();
-    println!("Part 2: {}", part2);
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_map(input))
+    }
+
+    fn part1(map: &Self::Input) -> Result<u64> {
+        Ok(all_distances(map, PART1_EXPANSION_SCALE).iter().sum())
+    }
+
+    fn part2(map: &Self::Input) -> Result<u64> {
+        Ok(all_distances(map, PART2_EXPANSION_SCALE).iter().sum())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    aocutils::examples!(Day11, part1);
+
     static EXAMPLE_INPUT: &str = "...#......\n.......#..\n#.........\n..........\n......#...\n.#........\n.........#\n..........\n.......#..\n#...#.....";
 
     #[test]
@@ -182,7 +179,7 @@ mod tests {
         let map = parse_map(EXAMPLE_INPUT);
         let expansions = get_map_expansions(&map);
 
-        let actual = vec![(3, 0), (7, 1), (0, 2), (6, 4), (1, 5), (9, 6), (7, 8), (0, 9), (4, 9)].iter().map(|c| expand_coordinate(c, &expansions, PART1_EXPANSION_SCALE)).collect::<Vec<Coordinate>>();
+        let actual = [(3, 0), (7, 1), (0, 2), (6, 4), (1, 5), (9, 6), (7, 8), (0, 9), (4, 9)].iter().map(|c| expand_coordinate(c, &expansions, PART1_EXPANSION_SCALE)).collect::<Vec<Coordinate>>();
         let expected = vec![(4, 0), (9, 1), (0, 2), (8, 5), (1, 6), (12, 7), (9, 10), (0, 11), (5, 11)];
 
         assert_eq!(actual, expected);
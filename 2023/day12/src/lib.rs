@@ -1,9 +1,9 @@
-use std::fs;
 
 use itertools::Itertools;
+use aocutils::{Result, Solution, Unsolved};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct SpringsGroup {
+pub struct SpringsGroup {
     conditions: Vec<char>,
     group_lengths: Vec<u32>
 }
@@ -71,18 +71,35 @@ fn find_combinations(group: &SpringsGroup) -> Vec<SpringsGroup> {
     output
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/input.txt").expect("should be able to read input");
-    let groups = input.lines().map(|l| parse_line(l)).collect_vec();
+pub struct Day12;
 
-    let part1 = groups.iter().map(|g| find_combinations(g).len()).sum::<usize>();
-    println!("Part 1: {}", part1);
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Input = Vec<SpringsGroup>;
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(parse_line).collect())
+    }
+
+    fn part1(groups: &Self::Input) -> Result<usize> {
+        Ok(groups.iter().map(|g| find_combinations(g).len()).sum())
+    }
+
+    fn part2(_: &Self::Input) -> Result<Unsolved> {
+        Ok(Unsolved)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    aocutils::examples!(Day12, part1);
+
     static EXAMPLE_INPUT: &str = "???.### 1,1,3\n.??..??...?##. 1,1,3\n?#?#?#?#?#?#?#? 1,3,1,6\n????.#...#... 4,1,1\n????.######..#####. 1,6,5\n?###???????? 3,2,1";
 
     #[test]
@@ -96,16 +113,14 @@ mod tests {
     #[test]
     fn test_is_valid() {
         assert_eq!(
-            vec![
-                "#.#.### 1,1,3",
+            ["#.#.### 1,1,3",
                 ".#...#....###. 1,1,3",
                 ".#.###.#.###### 1,3,1,6",
                 "####.#...#... 4,1,1",
                 "#....######..#####. 1,6,5",
                 ".###.##....# 3,2,1",
                 "....### 1,1,3",
-                "##..### 1,1,3"
-            ].iter().map(|l| parse_line(l).is_valid()).collect::<Vec<bool>>(),
+                "##..### 1,1,3"].iter().map(|l| parse_line(l).is_valid()).collect::<Vec<bool>>(),
             vec![true, true, true, true, true, true, false, false]
         )
     }
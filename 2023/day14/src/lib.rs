@@ -0,0 +1,166 @@
+
+use aocutils::{Direction4, Grid, Point, Result, Solution, Unsolved};
+
+type Platform = Grid<char>;
+
+fn parse_platform(s: &str) -> Result<Platform> {
+    Grid::parse(s, |c| c)
+}
+
+fn move_rock_step(p: &mut Platform, pos: (usize, usize), direction: Direction4) -> Option<(usize, usize)> {
+    // moving off the top or left edge gives a negative position, which can't be converted back
+    let dest = direction.step(Point::from_usize(pos)?).to_usize()?;
+
+    if p.get(dest.0, dest.1) != Some(&'.') {
+        return None;
+    }
+    
+    let src_char = p[pos];
+    
+    p[pos] = '.';
+    p[dest] = src_char;
+    
+    Some(dest)
+}
+
+fn transform_pos(i: usize, max_dimensions: (usize, usize), direction: Direction4) -> (usize, usize) {
+    match direction {
+        Direction4::South => (i % max_dimensions.0, max_dimensions.1 - (i / max_dimensions.0) - 1),
+        Direction4::West => (i / max_dimensions.1, i % max_dimensions.1),
+        Direction4::East => (max_dimensions.0 - (i / max_dimensions.1) - 1, i % max_dimensions.1),
+        Direction4::North => (i % max_dimensions.0, i / max_dimensions.0)
+    }
+}
+
+fn tilt_platform(p: &mut Platform, direction: Direction4) {
+    let max_dimensions = (p.width(), p.height());
+
+    for i in 0..(max_dimensions.0 * max_dimensions.1) {
+        let (x, y) = transform_pos(i, (max_dimensions.0, max_dimensions.1), direction);
+
+        if p[(x, y)] != 'O' {
+            continue;
+        }
+
+        let mut pos = (x, y);
+
+        while let Some(new_pos) = move_rock_step(p, pos, direction) {
+            pos = new_pos
+        }
+    }
+}
+
+// only used by tests until part 2 is finished
+#[allow(dead_code)]
+fn cycle_platform(p: &mut Platform, repeat: usize) {
+    for i in 0..repeat {
+        if i % 100000 == 0 {
+            println!("cycling {}%...", (i / repeat) * 100);
+        }
+
+        for direction in [Direction4::North, Direction4::West, Direction4::South, Direction4::East] {
+            tilt_platform(p, direction);
+        }
+    }
+}
+
+fn calculate_load(p: &Platform) -> usize {
+    let mut load = 0;
+    
+    for ((_, y), value) in p.iter() {
+        if value != &'O' {
+            continue;
+        }
+        
+        load += p.height() - y;
+    }
+    
+    load
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input = Platform;
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_platform(input)
+    }
+
+    fn part1(platform: &Self::Input) -> Result<usize> {
+        let mut platform = platform.clone();
+        tilt_platform(&mut platform, Direction4::North);
+        Ok(calculate_load(&platform))
+    }
+
+    fn part2(_: &Self::Input) -> Result<Unsolved> {
+        Ok(Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    static INITIAL_EXAMPLE: &str = "O....#....\nO.OO#....#\n.....##...\nOO.#O....O\n.O.....O#.\nO.#..O.#.#\n..O..#O..O\n.......O..\n#....###..\n#OO..#....";
+    static FINISHED_EXAMPLE: &str = "OOOO.#.O..\nOO..#....#\nOO..O##..O\nO..#.OO...\n........#.\n..#....#.#\n..O..#.O.O\n..O.......\n#....###..\n#....#....";
+    static ONE_CYCLE_EXAMPLE: &str = ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n.O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#....";
+    static TWO_CYCLES_EXAMPLE: &str = ".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n.O#...O#.#\n....O#...O\n.......OOO\n#..OO###..\n#.OOO#...O";
+    static THREE_CYCLES_EXAMPLE: &str = ".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n.O#...O#.#\n....O#...O\n.......OOO\n#...O###.O\n#.OOO#...O";
+    
+    fn print_platform(p: &Platform) {
+        println!("{}", p);
+    }
+    
+    #[test]
+    fn test_tilt_platform() {
+        let mut actual = parse_platform(INITIAL_EXAMPLE).unwrap();
+        tilt_platform(&mut actual, Direction4::North);
+        
+        let expected = parse_platform(FINISHED_EXAMPLE).unwrap();
+        
+        print_platform(&actual);
+        
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_calculate_load() {
+        let actual = calculate_load(&parse_platform(FINISHED_EXAMPLE).unwrap());
+        let expected = 136;
+        
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_transform_pos() {
+        assert_eq!(transform_pos(5, (10, 10), Direction4::North), (5, 0));
+        assert_eq!(transform_pos(5, (10, 10), Direction4::East), (9, 5));
+        assert_eq!(transform_pos(5, (10, 10), Direction4::South), (5, 9));
+        assert_eq!(transform_pos(5, (10, 10), Direction4::West), (0, 5));
+
+        assert_eq!(transform_pos(10, (10, 10), Direction4::North), (0, 1));
+        assert_eq!(transform_pos(10, (10, 10), Direction4::East), (8, 0));
+        assert_eq!(transform_pos(10, (10, 10), Direction4::South), (0, 8));
+        assert_eq!(transform_pos(10, (10, 10), Direction4::West), (1, 0));
+    }
+
+    #[test]
+    fn test_cycle_platform() {
+        let mut actual = parse_platform(INITIAL_EXAMPLE).unwrap();
+
+        cycle_platform(&mut actual, 1);
+        assert_eq!(actual, parse_platform(ONE_CYCLE_EXAMPLE).unwrap());
+
+        cycle_platform(&mut actual, 1);
+        assert_eq!(actual, parse_platform(TWO_CYCLES_EXAMPLE).unwrap());
+
+        cycle_platform(&mut actual, 1);
+        assert_eq!(actual, parse_platform(THREE_CYCLES_EXAMPLE).unwrap());
+    }
+}
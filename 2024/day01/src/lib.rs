@@ -1,9 +1,13 @@
-use std::error::Error;
+use aocutils::{Result, Solution};
 
-fn parse_input(input: Vec<Vec<String>>) -> Vec<Vec<i64>> {
+fn parse_input(input: &str) -> Vec<Vec<i64>> {
     input
-        .iter()
-        .map(|v| v.iter().map(|x| x.parse::<i64>().unwrap()).collect())
+        .lines()
+        .map(|v| {
+            v.split_whitespace()
+                .map(|x| x.parse::<i64>().unwrap())
+                .collect()
+        })
         .collect()
 }
 
@@ -43,31 +47,27 @@ fn product_similarity_score(left: Vec<i64>, right: Vec<i64>) -> i64 {
         .sum()
 }
 
-fn process_part1(input: Vec<Vec<String>>) -> u64 {
-    let (left, right) = split_vec(transpose(parse_input(input)));
-    total_pair_distance(left, right)
-}
+pub struct Day01;
 
-fn process_part2(input: Vec<Vec<String>>) -> i64 {
-    let (left, right) = split_vec(transpose(parse_input(input)));
-    product_similarity_score(left, right)
-}
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    println!(
-        "part 1: {}",
-        process_part1(aocutils::read_input_lines_whitespace("input")?)
-    );
-    println!(
-        "example part 2: {}",
-        process_part2(aocutils::read_input_lines_whitespace("example")?)
-    );
-    println!(
-        "part 2: {}",
-        process_part2(aocutils::read_input_lines_whitespace("input")?)
-    );
-
-    Ok(())
+    type Input = (Vec<i64>, Vec<i64>);
+    type Part1 = u64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(split_vec(transpose(parse_input(input))))
+    }
+
+    fn part1((left, right): &Self::Input) -> Result<u64> {
+        Ok(total_pair_distance(left.clone(), right.clone()))
+    }
+
+    fn part2((left, right): &Self::Input) -> Result<i64> {
+        Ok(product_similarity_score(left.clone(), right.clone()))
+    }
 }
 
 #[cfg(test)]
@@ -77,7 +77,8 @@ mod tests {
     #[test]
     fn part1_example() {
         assert_eq!(
-            process_part1(aocutils::read_input_lines_whitespace("example").unwrap()),
+            Day01::part1(&Day01::parse(&aocutils::read_input!("example").unwrap()).unwrap())
+                .unwrap(),
             11
         )
     }
@@ -85,7 +86,8 @@ mod tests {
     #[test]
     fn part2_example() {
         assert_eq!(
-            process_part2(aocutils::read_input_lines_whitespace("example").unwrap()),
+            Day01::part2(&Day01::parse(&aocutils::read_input!("example").unwrap()).unwrap())
+                .unwrap(),
             31
         )
     }
@@ -1,15 +1,16 @@
 use std::ops::Sub;
 
-fn convert_report(report: Vec<String>) -> Vec<i64> {
-    report.iter().map(|v| v.parse().unwrap()).collect()
+use aocutils::{Result, Solution};
+
+fn convert_report(report: &str) -> Vec<i64> {
+    report
+        .split_whitespace()
+        .map(|v| v.parse().unwrap())
+        .collect()
 }
 
-fn check_report(report: &Vec<i64>, min: i64, max: i64) -> bool {
-    let diff: Vec<i64> = report
-        .windows(2)
-        .into_iter()
-        .map(|c| c[0].sub(c[1]))
-        .collect();
+fn check_report(report: &[i64], min: i64, max: i64) -> bool {
+    let diff: Vec<i64> = report.windows(2).map(|c| c[0].sub(c[1])).collect();
 
     let ascending = diff.first().unwrap().is_positive();
 
@@ -19,19 +20,19 @@ fn check_report(report: &Vec<i64>, min: i64, max: i64) -> bool {
         .all(|n| n.is_positive() == ascending && n.abs() >= min && n.abs() <= max)
 }
 
-fn remove_levels(report: &Vec<i64>) -> Vec<Vec<i64>> {
+fn remove_levels(report: &[i64]) -> Vec<Vec<i64>> {
     report
-        .into_iter()
+        .iter()
         .enumerate()
         .map(|(i, _)| {
-            let mut new_report = report.clone();
+            let mut new_report = report.to_vec();
             new_report.remove(i);
             new_report
         })
         .collect()
 }
 
-fn check_report_try_remove_level(report: &Vec<i64>, min: i64, max: i64) -> bool {
+fn check_report_try_remove_level(report: &[i64], min: i64, max: i64) -> bool {
     // first try checking with the full report
     if check_report(report, min, max) {
         return true;
@@ -43,28 +44,33 @@ fn check_report_try_remove_level(report: &Vec<i64>, min: i64, max: i64) -> bool
         .any(|r| check_report(r, min, max))
 }
 
-fn main() {
-    let reports: Vec<Vec<i64>> = aocutils::read_input_lines_whitespace("input")
-        .unwrap()
-        .into_iter()
-        .map(|line| convert_report(line))
-        .collect();
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i64>>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!(
-        "part 1: {}",
-        reports
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(convert_report).collect())
+    }
+
+    fn part1(reports: &Self::Input) -> Result<usize> {
+        Ok(reports
             .iter()
             .filter(|report| check_report(report, 1, 3))
-            .count()
-    );
+            .count())
+    }
 
-    println!(
-        "part 2: {}",
-        reports
+    fn part2(reports: &Self::Input) -> Result<usize> {
+        Ok(reports
             .iter()
             .filter(|report| check_report_try_remove_level(report, 1, 3))
-            .count()
-    );
+            .count())
+    }
 }
 
 #[cfg(test)]
@@ -74,9 +80,9 @@ mod tests {
     #[test]
     fn test_part1_check_report() {
         let expected: Vec<bool> = Vec::from([true, false, false, false, false, true]);
-        let actual = aocutils::read_input_lines_whitespace("example")
+        let actual = aocutils::read_input_lines!("example")
             .unwrap()
-            .into_iter()
+            .iter()
             .map(|line| check_report(&convert_report(line), 1, 3))
             .collect::<Vec<bool>>();
 
@@ -100,9 +106,9 @@ mod tests {
     #[test]
     fn test_part2_check_report() {
         let expected: Vec<bool> = Vec::from([true, false, false, true, true, true]);
-        let actual = aocutils::read_input_lines_whitespace("example")
+        let actual = aocutils::read_input_lines!("example")
             .unwrap()
-            .into_iter()
+            .iter()
             .map(|line| check_report_try_remove_level(&convert_report(line), 1, 3))
             .collect::<Vec<bool>>();
 
@@ -1,8 +1,9 @@
+use aocutils::{Result, Solution};
 use regex::Regex;
 use std::{error::Error, ops::Mul};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-enum Instruction {
+pub enum Instruction {
     Disable,
     Enable,
     Mul(i64, i64),
@@ -12,7 +13,7 @@ fn parse_instructions(s: &str) -> Result<Vec<Instruction>, Box<dyn Error>> {
     // use regex to extract mul instructions that match the format
     let re = Regex::new(r"(mul\(-?\d{1,3},-?\d{1,3}\))|(do\(\))|(don't\(\))")?;
 
-    Ok(re
+    re
         // loop over regex captures
         .captures_iter(s)
         .map(|c| {
@@ -39,10 +40,10 @@ fn parse_instructions(s: &str) -> Result<Vec<Instruction>, Box<dyn Error>> {
             }
         })
         // bring together our map into a vec/list
-        .collect::<Result<Vec<Instruction>, Box<dyn Error>>>()?)
+        .collect::<Result<Vec<Instruction>, Box<dyn Error>>>()
 }
 
-fn process_instructions(instructions: &Vec<Instruction>) -> i64 {
+fn process_instructions(instructions: &[Instruction]) -> i64 {
     let mut result = 0;
     let mut execution_enabled = true;
 
@@ -61,25 +62,34 @@ fn process_instructions(instructions: &Vec<Instruction>) -> i64 {
     result
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = aocutils::read_input_lines("input")?.join("");
-    let instructions = parse_instructions(&input)?;
+pub struct Day03;
 
-    println!(
-        "part 1: {}",
-        process_instructions(
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Instruction>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_instructions(&input.lines().collect::<String>()).unwrap())
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<i64> {
+        Ok(process_instructions(
             // filter out only mul instructions
             &instructions
-                .clone()
-                .into_iter()
+                .iter()
                 .filter(|i| matches!(i, Instruction::Mul(_, _)))
-                .collect()
-        )
-    );
-
-    println!("part 2: {}", process_instructions(&instructions));
+                .cloned()
+                .collect::<Vec<_>>(),
+        ))
+    }
 
-    Ok(())
+    fn part2(instructions: &Self::Input) -> Result<i64> {
+        Ok(process_instructions(instructions))
+    }
 }
 
 #[cfg(test)]
@@ -89,7 +99,7 @@ mod tests {
     #[test]
     fn test_parse_instructions_example_part1() -> Result<(), Box<dyn Error>> {
         assert_eq!(
-            parse_instructions(&aocutils::read_input_lines("example")?.join(""))?,
+            parse_instructions(&aocutils::read_input_lines!("example")?.join(""))?,
             Vec::from([
                 Instruction::Mul(2, 4),
                 Instruction::Mul(5, 5),
@@ -104,7 +114,7 @@ mod tests {
     #[test]
     fn test_parse_instructions_example_part2() -> Result<(), Box<dyn Error>> {
         assert_eq!(
-            parse_instructions(&aocutils::read_input_lines("example2")?.join(""))?,
+            parse_instructions(&aocutils::read_input_lines!("example2")?.join(""))?,
             Vec::from([
                 Instruction::Mul(2, 4),
                 Instruction::Disable,
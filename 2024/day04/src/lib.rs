@@ -1,8 +1,6 @@
-use std::error::Error;
+use aocutils::{Grid, Result, Solution};
 
-use aocutils;
-
-type WordSearch = Vec<Vec<char>>;
+type WordSearch = Grid<char>;
 
 // only_diagonal will only search in the diagonal directions for part 2
 // offset will start searching that amount backwards to begin with. for part 2 this is set to 1 so
@@ -31,21 +29,14 @@ fn check_position(m: &WordSearch, x: usize, y: usize, target_word: &str, only_di
             // loop over each character in the word, skipping the first as it's already been checked
             for (i, char) in to_check.iter().enumerate() {
                 // calculate the new search position
-                let cur_x: i32 = x as i32 + (xi * (i as i32 - offset as i32));
-                let cur_y: i32 = y as i32 + (yi * (i as i32 - offset as i32));
-
-                // check the bounds of the new position
-                if cur_x < 0 || cur_x >= m[0].len() as i32 || cur_y < 0 || cur_y >= m.len() as i32 {
-//                    println!("  {},{} out of bounds", cur_x, cur_y);
-                    is_match = false;
-                    break;
-                }
+                let cur_x = x as isize + (xi * (i as isize - offset as isize));
+                let cur_y = y as isize + (yi * (i as isize - offset as isize));
 
-//                println!("  {},{} = {}", cur_x, cur_y, &m[cur_y as usize][cur_x as usize]);
+//                println!("  {},{} = {:?}", cur_x, cur_y, m.get_signed(cur_x, cur_y));
 
-                // check the new position matches the character in the word that is being searched,
-                // if not stop searching this one
-                if &m[cur_y as usize][cur_x as usize] != char {
+                // check the new position is in bounds and matches the character in the word that is being
+                // searched, if not stop searching this one
+                if m.get_signed(cur_x, cur_y) != Some(char) {
 //                    println!("    - no match");
                     is_match = false;
                     break;
@@ -66,8 +57,8 @@ fn check_position(m: &WordSearch, x: usize, y: usize, target_word: &str, only_di
 fn check_all_part1(m: &WordSearch) -> usize {
     let mut count = 0;
 
-    for y in 0..m.len() {
-        for x in 0..m[y].len() {
+    for y in 0..m.height() {
+        for x in 0..m.width() {
             count += check_position(m, x, y, "XMAS", false, 0);
         }
     }
@@ -81,8 +72,8 @@ fn check_all_part2(m: &WordSearch) -> usize {
 
     let mut count = 0;
 
-    for y in 0..m.len() {
-        for x in 0..m[y].len() {
+    for y in 0..m.height() {
+        for x in 0..m.width() {
             let res = check_position(m, x, y, "MAS", true, 1);
             // only count two matches (to make an x)
             if res == 2 {
@@ -94,13 +85,27 @@ fn check_all_part2(m: &WordSearch) -> usize {
     count
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = aocutils::read_input_grid("input")?;
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
 
-    println!("part 1: {}", check_all_part1(&input));
-    println!("part 2: {}", check_all_part2(&input));
+    type Input = WordSearch;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, |c| c)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(check_all_part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(check_all_part2(input))
+    }
 }
 
 #[cfg(test)]
@@ -109,26 +114,26 @@ mod tests {
 
     #[test]
     fn test_check_position_part1_example() {
-        let input = aocutils::read_input_grid("example").unwrap();
+        let input = aocutils::read_input_grid!("example").unwrap();
         assert_eq!(check_position(&input, 3, 4, "XMAS", false, 0), 0);
         assert_eq!(check_position(&input, 3, 4, "SAMX", false, 0), 2);
     }
 
     #[test]
     fn test_check_all_part1_example() {
-        let input = aocutils::read_input_grid("example").unwrap();
+        let input = aocutils::read_input_grid!("example").unwrap();
         assert_eq!(check_all_part1(&input), 18);
     }
 
     #[test]
     fn test_check_position_part2_example() {
-        let input = aocutils::read_input_grid("example").unwrap();
+        let input = aocutils::read_input_grid!("example").unwrap();
         assert_eq!(check_position(&input, 2, 1, "MAS", true, 1), 2);
     }
 
     #[test]
     fn test_check_all_part2_example() {
-        let input = aocutils::read_input_grid("example").unwrap();
+        let input = aocutils::read_input_grid!("example").unwrap();
         assert_eq!(check_all_part2(&input), 9);
     }
 }
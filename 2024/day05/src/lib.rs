@@ -1,4 +1,6 @@
-fn parse_input(input: String) -> (Vec<(i64, i64)>, Vec<Vec<i64>>) {
+use aocutils::{Result, Solution};
+
+fn parse_input(input: &str) -> (Vec<(i64, i64)>, Vec<Vec<i64>>) {
     // split the input by two new lines to get each section
     let (rules, updates) = input.split_once("\n\n").unwrap();
 
@@ -19,7 +21,7 @@ fn parse_input(input: String) -> (Vec<(i64, i64)>, Vec<Vec<i64>>) {
     )
 }
 
-fn sort_update(update: &mut Vec<i64>, rules: &Vec<(i64, i64)>, exit_early: bool) -> bool {
+fn sort_update(update: &mut [i64], rules: &[(i64, i64)], exit_early: bool) -> bool {
     // has the update been changed at all?
     let mut overall_changed = false;
     // has the update been changed this iteration?
@@ -32,7 +34,7 @@ fn sort_update(update: &mut Vec<i64>, rules: &Vec<(i64, i64)>, exit_early: bool)
         // loop over each page number in the update
         for i in 0..update.len() {
             // create a read-only copy of update for filtering rules
-            let update_ro = update.clone();
+            let update_ro = update.to_vec();
             // loop over each rule that contains the number that is being operated on
             for rule in rules
                 .iter()
@@ -71,11 +73,11 @@ fn sort_update(update: &mut Vec<i64>, rules: &Vec<(i64, i64)>, exit_early: bool)
         }
     }
 
-    return overall_changed;
+    overall_changed
 }
 
 // for part 1, exit early will not sort any lists, so it gets the part 1 solution faster
-fn process(updates: &mut Vec<Vec<i64>>, rules: &Vec<(i64, i64)>, exit_early: bool) -> (i64, i64) {
+fn process(updates: &mut [Vec<i64>], rules: &[(i64, i64)], exit_early: bool) -> (i64, i64) {
     // for part 1, the sum of the middle numbers of all the lists that are sorted properly
     let mut unchanged_sum = 0;
     // for part 2, the sum of the middle numbers of all the lists that have been sorted
@@ -95,14 +97,27 @@ fn process(updates: &mut Vec<Vec<i64>>, rules: &Vec<(i64, i64)>, exit_early: boo
     (unchanged_sum, changed_sum)
 }
 
-fn main() {
-    let input = aocutils::read_input("input").unwrap();
-    let (rules, mut updates) = parse_input(input);
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
 
-    let (part1, part2) = process(&mut updates, &rules, false);
+    type Input = (Vec<(i64, i64)>, Vec<Vec<i64>>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input))
+    }
 
-    println!("part 1: {}", part1);
-    println!("part 2: {}", part2);
+    fn part1((rules, updates): &Self::Input) -> Result<i64> {
+        Ok(process(&mut updates.clone(), rules, true).0)
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Result<i64> {
+        Ok(process(&mut updates.clone(), rules, false).1)
+    }
 }
 
 #[cfg(test)]
@@ -111,8 +126,8 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let input = aocutils::read_input("example").unwrap();
-        let (rules, updates) = parse_input(input);
+        let input = aocutils::read_input!("example").unwrap();
+        let (rules, updates) = parse_input(&input);
 
         assert!(rules.contains(&(47, 53)));
         assert!(rules.contains(&(53, 13)));
@@ -122,16 +137,16 @@ mod tests {
 
     #[test]
     fn test_process_part_1() {
-        let input = aocutils::read_input("example").unwrap();
-        let (rules, mut updates) = parse_input(input);
+        let input = aocutils::read_input!("example").unwrap();
+        let (rules, mut updates) = parse_input(&input);
 
         assert_eq!(process(&mut updates, &rules, true).0, 143);
     }
 
     #[test]
     fn test_process_part_2() {
-        let input = aocutils::read_input("example").unwrap();
-        let (rules, mut updates) = parse_input(input);
+        let input = aocutils::read_input!("example").unwrap();
+        let (rules, mut updates) = parse_input(&input);
 
         assert_eq!(process(&mut updates, &rules, false).1, 123);
     }
@@ -0,0 +1,249 @@
+use std::collections::{HashMap, HashSet};
+
+use aocutils::{
+    viz::{Animation, Color, Palette},
+    Direction4, Error, Grid, Point, Result, Solution,
+};
+
+type Map = Grid<bool>;
+
+fn parse_map(input: &str) -> Result<(Map, Point)> {
+    let cells = Grid::try_parse(input, |c| matches!(c, '#' | '.' | '^').then_some(c))?;
+    let guard_pos = cells
+        .find(|c| *c == '^')
+        .and_then(Point::from_usize)
+        .ok_or_else(|| Error::invalid_state("the map has no guard"))?;
+
+    Ok((cells.map(|c| *c == '#'), guard_pos))
+}
+
+/// Draw the map with the guard at `pos` and an arrow where it has already been, in the direction
+/// it first went
+pub fn draw_map(map: &Map, pos: Point, history: &[(Point, Direction4)]) -> String {
+    let mut visited = HashMap::new();
+    for &(point, direction) in history {
+        visited.entry(point).or_insert(direction);
+    }
+
+    let mut drawing = String::new();
+    for (y, row) in map.rows().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            let point = Point::from_usize((x, y)).unwrap();
+
+            if point == pos {
+                drawing.push('%');
+            } else if let Some(direction) = visited.get(&point) {
+                drawing.push(direction.arrow());
+            } else if *cell {
+                drawing.push('#');
+            } else {
+                drawing.push('.');
+            }
+        }
+        drawing.push('\n');
+    }
+
+    drawing
+}
+
+fn pathfind(
+    map: &Map,
+    start_pos: Point,
+    start_direction: Direction4,
+) -> (bool, Vec<(Point, Direction4)>) {
+    let mut history = Vec::from([(start_pos, start_direction)]);
+    let mut pos = start_pos;
+    let mut direction = start_direction;
+    let mut i = 0;
+
+    loop {
+        let new_pos = direction.step(pos);
+
+        // check bounds
+        let Some(&blocked) = map.get_point(new_pos) else {
+            return (false, history);
+        };
+
+        if i >= 10 {
+            // we've been stuck for a while
+            return (false, history);
+        }
+
+        // quit if visited before
+        if history.contains(&(new_pos, direction)) {
+            return (true, history);
+        }
+
+        if blocked {
+            // turn
+            direction = direction.rotate_cw();
+            i += 1;
+            continue;
+        }
+
+        i = 0;
+        pos = new_pos;
+        history.push((pos, direction));
+    }
+}
+
+fn unique_positions(history: &[(Point, Direction4)]) -> usize {
+    history
+        .iter()
+        .map(|x| x.0)
+        .collect::<HashSet<Point>>()
+        .len()
+}
+
+fn find_loops(map: &Map, history: &[(Point, Direction4)]) -> Vec<Point> {
+    let mut new_blocks = Vec::new();
+
+    for &(pos, direction) in history {
+        for d in [
+            direction,
+            direction.rotate_cw(),
+            direction.opposite(),
+            direction.rotate_ccw(),
+        ] {
+            let block_pos = d.step(pos);
+
+            // check bounds
+            if map.get_point(pos).is_none() {
+                break;
+            }
+            let Some(block) = block_pos
+                .to_usize()
+                .filter(|&(x, y)| map.get(x, y).is_some())
+            else {
+                if d == direction {
+                    break;
+                } else {
+                    continue;
+                }
+            };
+
+            // there's already a block here, rotate and try again
+            if map[block] {
+                continue;
+            }
+
+            // update the map
+            let mut new_map = map.clone();
+            new_map[block] = true;
+
+            let (loop_detected, _) = pathfind(&new_map, pos, d);
+
+            if loop_detected {
+                new_blocks.push(block_pos);
+            }
+
+            break;
+        }
+    }
+
+    new_blocks
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input = (Map, Vec<(Point, Direction4)>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (map, guard_pos) = parse_map(input)?;
+        let (_, path) = pathfind(&map, guard_pos, Direction4::North);
+
+        Ok((map, path))
+    }
+
+    fn part1((_, path): &Self::Input) -> Result<usize> {
+        Ok(unique_positions(path))
+    }
+
+    fn part2((map, path): &Self::Input) -> Result<usize> {
+        Ok(find_loops(map, path).len())
+    }
+
+    fn animate((map, path): &Self::Input) -> Option<Animation> {
+        let (map, path) = (map.clone(), path.clone());
+        let frames = (0..path.len()).map(move |i| draw_map(&map, path[i].0, &path[..i]));
+
+        Some(
+            Animation::new(frames).fps(30.0).palette(
+                Palette::new()
+                    .with("#", Color::BrightBlack)
+                    .with("^>v<", Color::Yellow)
+                    .with("%", Color::BrightRed),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
+
+    #[test]
+    fn test_parse_map() {
+        let (_, guard_pos) = parse_map(EXAMPLE_INPUT).unwrap();
+        assert_eq!(guard_pos, Point::new(4, 6));
+    }
+
+    #[test]
+    fn test_pathfind_unique_positions() {
+        let (map, guard_pos) = parse_map(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            unique_positions(&pathfind(&map, guard_pos, Direction4::North).1),
+            41
+        );
+    }
+
+    #[test]
+    fn test_loops() {
+        let (map, guard_pos) = parse_map(EXAMPLE_INPUT).unwrap();
+        let (_, history) = pathfind(&map, guard_pos, Direction4::North);
+        let blocks = find_loops(&map, &history);
+
+        assert!(blocks.contains(&Point::new(3, 6)));
+        assert!(blocks.contains(&Point::new(6, 7)));
+        assert!(blocks.contains(&Point::new(7, 7)));
+        assert!(blocks.contains(&Point::new(1, 8)));
+        assert!(blocks.contains(&Point::new(3, 8)));
+        assert!(blocks.contains(&Point::new(7, 9)));
+        assert_eq!(blocks.len(), 6);
+    }
+
+    #[test]
+    fn test_custom_case_1() {
+        let (map, guard_pos) = parse_map("..#.\n...#\n..^.").unwrap();
+        let (_, history) = pathfind(&map, guard_pos, Direction4::North);
+
+        assert_eq!(unique_positions(&history), 2);
+        assert_eq!(find_loops(&map, &history).len(), 0);
+    }
+
+    #[test]
+    fn test_custom_case_2() {
+        let (map, guard_pos) = parse_map(".#.\n#.#\n#^.\n...").unwrap();
+        let (_, history) = pathfind(&map, guard_pos, Direction4::North);
+
+        assert_eq!(unique_positions(&history), 3);
+        assert_eq!(find_loops(&map, &history).len(), 1);
+    }
+
+    #[test]
+    fn test_custom_case_3() {
+        let (map, guard_pos) = parse_map(".#.\n..#\n#^.\n...").unwrap();
+        let (_, history) = pathfind(&map, guard_pos, Direction4::North);
+
+        assert_eq!(unique_positions(&history), 3);
+        assert_eq!(find_loops(&map, &history).len(), 1);
+    }
+}
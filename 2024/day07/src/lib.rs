@@ -1,5 +1,6 @@
 use std::iter::zip;
 
+use aocutils::{parse::FromInput, Result, Solution};
 use itertools::Itertools;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -13,32 +14,19 @@ const PART1_OPERATIONS: [Operation; 2] = [Operation::Add, Operation::Multiply];
 const PART2_OPERATIONS: [Operation; 3] =
     [Operation::Add, Operation::Multiply, Operation::Concatenate];
 
-#[derive(Debug, PartialEq, Eq)]
-struct Equation {
+#[derive(Debug, PartialEq, Eq, FromInput)]
+#[aoc(pattern = "{result}: {numbers}")]
+pub struct Equation {
     result: i64,
     numbers: Vec<i64>,
 }
 
-fn parse_input(input: &str) -> Vec<Equation> {
-    input
-        .lines()
-        .map(|line| {
-            let (result, numbers) = line.split_once(": ").unwrap();
-
-            Equation {
-                result: result.parse().unwrap(),
-                numbers: numbers
-                    .split_whitespace()
-                    .map(|n| n.parse().unwrap())
-                    .collect(),
-            }
-        })
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<Equation>> {
+    Equation::from_input(input)
 }
 
 fn calculate_brute_force(eq: &Equation, operations: &[Operation]) -> bool {
-    let op_combos: Vec<Vec<&Operation>> = std::iter::repeat(operations)
-        .take(eq.numbers.len() - 1)
+    let op_combos: Vec<Vec<&Operation>> = std::iter::repeat_n(operations, eq.numbers.len() - 1)
         .multi_cartesian_product()
         .collect();
 
@@ -62,29 +50,47 @@ fn calculate_brute_force(eq: &Equation, operations: &[Operation]) -> bool {
     false
 }
 
-fn sum_valid_equations(eqs: &Vec<Equation>, operations: &[Operation]) -> i64 {
+fn sum_valid_equations(eqs: &[Equation], operations: &[Operation]) -> i64 {
     eqs.iter()
         .filter(|eq| calculate_brute_force(eq, operations))
         .map(|eq| eq.result)
         .sum()
 }
 
-fn main() {
-    let eqs = parse_input(&aocutils::read_input("input").unwrap());
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Input = Vec<Equation>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(eqs: &Self::Input) -> Result<i64> {
+        Ok(sum_valid_equations(eqs, &PART1_OPERATIONS))
+    }
 
-    println!("part 1: {}", sum_valid_equations(&eqs, &PART1_OPERATIONS));
-    println!("part 2: {}", sum_valid_equations(&eqs, &PART2_OPERATIONS));
+    fn part2(eqs: &Self::Input) -> Result<i64> {
+        Ok(sum_valid_equations(eqs, &PART2_OPERATIONS))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    aocutils::examples!(Day07);
+
     const EXAMPLE_INPUT: &str = "190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20";
 
     #[test]
     fn test_parse_input() {
-        let parsed = parse_input(EXAMPLE_INPUT);
+        let parsed = parse_input(EXAMPLE_INPUT).unwrap();
         assert_eq!(parsed.len(), 9);
         assert_eq!(
             parsed[0],
@@ -99,6 +105,7 @@ mod tests {
     fn test_calculate_brute_force_part1() {
         assert_eq!(
             parse_input(EXAMPLE_INPUT)
+                .unwrap()
                 .iter()
                 .map(|eq| calculate_brute_force(eq, &PART1_OPERATIONS))
                 .collect::<Vec<bool>>(),
@@ -110,17 +117,18 @@ mod tests {
     fn test_calculate_brute_force() {
         assert_eq!(
             parse_input(EXAMPLE_INPUT)
+                .unwrap()
                 .iter()
-                .map(|eq| calculate_brute_force(eq))
+                .map(|eq| calculate_brute_force(eq, &PART2_OPERATIONS))
                 .collect::<Vec<bool>>(),
-            vec![true, true, false, false, false, false, false, false, true]
+            vec![true, true, false, true, true, false, true, false, true]
         )
     }
 
     #[test]
     fn test_part1() {
         assert_eq!(
-            sum_valid_equations(&parse_input(EXAMPLE_INPUT), &PART1_OPERATIONS),
+            sum_valid_equations(&parse_input(EXAMPLE_INPUT).unwrap(), &PART1_OPERATIONS),
             3749
         );
     }
@@ -128,7 +136,7 @@ mod tests {
     #[test]
     fn test_part2() {
         assert_eq!(
-            sum_valid_equations(&parse_input(EXAMPLE_INPUT), &PART2_OPERATIONS),
+            sum_valid_equations(&parse_input(EXAMPLE_INPUT).unwrap(), &PART2_OPERATIONS),
             11387
         );
     }
@@ -1,44 +1,46 @@
-use colored::*;
+use aocutils::{
+    viz::{Animation, Color, Palette},
+    Result, Solution,
+};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
 type Coords = (usize, usize);
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Map {
+pub struct Map {
     antennas: HashMap<Coords, char>,
     antinodes: HashMap<Coords, char>,
     bounds: Coords,
 }
 
 impl Map {
-    fn draw(&self) -> () {
-        print!("   ");
-        for x in 0..self.bounds.0 {
-            print!("{}", x % 10);
-        }
-        println!("");
+    /// Draw the antennas, with a `#` for each antinode that isn't under one
+    pub fn draw(&self) -> String {
+        let mut drawing = String::new();
+
         for y in 0..self.bounds.1 {
-            print!("{:>2} ", y);
             for x in 0..self.bounds.0 {
-                if let Some(c) = self.antennas.get(&(x, y)) {
-                    print!("{}", c.to_string().red());
-                } else if let Some(c) = self.antinodes.get(&(x, y)) {
-                    print!("{}", c.to_string().green());
+                if let Some(&c) = self.antennas.get(&(x, y)) {
+                    drawing.push(c);
+                } else if self.antinodes.contains_key(&(x, y)) {
+                    drawing.push('#');
                 } else {
-                    print!("{}", ".".white());
+                    drawing.push('.');
                 }
             }
-            println!("");
+            drawing.push('\n');
         }
+
+        drawing
     }
 
-    fn process_antinodes(&mut self, part2: bool) -> () {
+    fn process_antinodes(&mut self, part2: bool) {
         // create a list of coordinates where each frequency has antennas
         let mut freq_antennas: HashMap<char, Vec<Coords>> = HashMap::new();
 
         for (k, v) in self.antennas.iter() {
-            freq_antennas.entry(*v).or_insert(Vec::new()).push(*k);
+            freq_antennas.entry(*v).or_default().push(*k);
         }
 
         // for each frequency
@@ -101,22 +103,54 @@ fn parse_input(input: &str) -> Map {
     }
 }
 
-fn main() {
-    let m = parse_input(&aocutils::read_input("input").unwrap());
-
-    let mut m1 = m.clone();
-    m1.process_antinodes(false);
-    println!(
-        "part 1: {}",
-        m1.antinodes.keys().collect::<HashSet<_>>().len()
-    );
-
-    let mut m2 = m;
-    m2.process_antinodes(true);
-    println!(
-        "part 2: {}",
-        m2.antinodes.keys().collect::<HashSet<_>>().len()
-    );
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(m: &Self::Input) -> Result<usize> {
+        let mut m = m.clone();
+        m.process_antinodes(false);
+        Ok(m.antinodes.keys().collect::<HashSet<_>>().len())
+    }
+
+    fn part2(m: &Self::Input) -> Result<usize> {
+        let mut m = m.clone();
+        m.process_antinodes(true);
+        Ok(m.antinodes.keys().collect::<HashSet<_>>().len())
+    }
+
+    fn animate(m: &Self::Input) -> Option<Animation> {
+        // the antennas on their own, then with the antinodes from each part
+        let frames = [None, Some(false), Some(true)].map(|part2| {
+            let mut m = m.clone();
+            if let Some(part2) = part2 {
+                m.process_antinodes(part2);
+            }
+            m.draw()
+        });
+
+        let antennas = ('0'..='9')
+            .chain('a'..='z')
+            .chain('A'..='Z')
+            .collect::<String>();
+        Some(
+            Animation::new(frames).fps(1.0).palette(
+                Palette::new()
+                    .with(&antennas, Color::Red)
+                    .with("#", Color::Green),
+            ),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -150,7 +184,7 @@ mod tests {
     fn test_process_antinodes_p1() {
         let mut m = parse_input(EXAMPLE_INPUT);
         m.process_antinodes(false);
-        m.draw();
+        println!("{}", m.draw());
 
         let mut actual_antinodes = m.antinodes.keys().unique().collect::<Vec<&Coords>>();
         let mut expected_antinodes: Vec<&Coords> = Vec::from([
@@ -180,7 +214,7 @@ mod tests {
     fn test_process_antinodes_p2() {
         let mut m = parse_input(EXAMPLE_INPUT);
         m.process_antinodes(true);
-        m.draw();
+        println!("{}", m.draw());
 
         let actual_antinodes = m.antinodes.keys().collect::<HashSet<_>>().len();
 
@@ -2,8 +2,10 @@ macro_rules! debug_println {
     ($($arg:tt)*) => (if ::std::cfg!(debug_assertions) { ::std::println!($($arg)*); })
 }
 
+use aocutils::{Result, Solution};
+
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct FilesystemBlock {
+pub struct FilesystemBlock {
     pub size: usize,
 
     // the file id will be set to none for free space
@@ -41,7 +43,7 @@ fn parse_input(input: &str) -> Vec<FilesystemBlock> {
     output
 }
 
-fn move_blocks(map: &mut Vec<FilesystemBlock>, move_whole: bool) -> () {
+fn move_blocks(map: &mut Vec<FilesystemBlock>, move_whole: bool) {
     // pointer for the current block that we're working on, to begin with we want to start from the right side
     let mut block_idx: i32 = map.len() as i32 - 1;
 
@@ -160,7 +162,7 @@ fn move_blocks(map: &mut Vec<FilesystemBlock>, move_whole: bool) -> () {
     }
 }
 
-fn checksum(map: &Vec<FilesystemBlock>) -> usize {
+fn checksum(map: &[FilesystemBlock]) -> usize {
     let mut sum = 0;
     let mut i = 0;
 
@@ -176,17 +178,31 @@ fn checksum(map: &Vec<FilesystemBlock>) -> usize {
     sum
 }
 
-fn main() {
-    let input = aocutils::read_input("input").unwrap();
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
 
-    let mut map1 = parse_input(&input);
-    let mut map2 = map1.clone();
+    type Input = Vec<FilesystemBlock>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input.trim()))
+    }
 
-    move_blocks(&mut map1, false);
-    println!("part 1: {}", checksum(&map1));
+    fn part1(map: &Self::Input) -> Result<usize> {
+        let mut map = map.clone();
+        move_blocks(&mut map, false);
+        Ok(checksum(&map))
+    }
 
-    move_blocks(&mut map2, true);
-    println!("part 2: {}", checksum(&map2));
+    fn part2(map: &Self::Input) -> Result<usize> {
+        let mut map = map.clone();
+        move_blocks(&mut map, true);
+        Ok(checksum(&map))
+    }
 }
 
 #[cfg(test)]
@@ -196,7 +212,7 @@ mod tests {
     const EXAMPLE_1: &str = "12345";
     const EXAMPLE_2: &str = "2333133121414131402";
 
-    fn filesystem_to_string(fs: &Vec<FilesystemBlock>) -> String {
+    fn filesystem_to_string(fs: &[FilesystemBlock]) -> String {
         // this expands the filesystem list into the format shown in the puzzle
 
         let mut buf = String::new();
@@ -231,7 +247,7 @@ mod tests {
 
     #[test]
     fn test_move_blocks_single_example_1() {
-        let mut map = parse_input(&EXAMPLE_1);
+        let mut map = parse_input(EXAMPLE_1);
         move_blocks(&mut map, false);
 
         assert_eq!(filesystem_to_string(&map), "022111222......");
@@ -239,7 +255,7 @@ mod tests {
 
     #[test]
     fn test_move_blocks_single_example_2() {
-        let mut map = parse_input(&EXAMPLE_2);
+        let mut map = parse_input(EXAMPLE_2);
         move_blocks(&mut map, false);
 
         assert_eq!(
@@ -250,7 +266,7 @@ mod tests {
 
     #[test]
     fn test_move_blocks_whole_example_2() {
-        let mut map = parse_input(&EXAMPLE_2);
+        let mut map = parse_input(EXAMPLE_2);
         move_blocks(&mut map, true);
 
         assert_eq!(
@@ -261,7 +277,7 @@ mod tests {
 
     #[test]
     fn test_checksum_example_1() {
-        let mut map = parse_input(&EXAMPLE_2);
+        let mut map = parse_input(EXAMPLE_2);
         move_blocks(&mut map, false);
         assert_eq!(checksum(&map), 1928);
     }
@@ -0,0 +1,95 @@
+use aocutils::{dijkstra_all, Grid, Result, Solution};
+
+// impassable tiles in the examples are `None`
+type Map = Grid<Option<u8>>;
+
+fn parse_input(input: &str) -> Result<Map> {
+    Grid::try_parse(input, |c| match c {
+        '.' => Some(None),
+        _ => c.to_digit(10).map(|height| Some(height as u8)),
+    })
+}
+
+fn get_trailhead_scores_and_ratings(map: &Map) -> (usize, usize) {
+    let mut total_score = 0;
+    let mut total_rating = 0;
+
+    for (trailhead, _) in map.iter().filter(|(_, &height)| height == Some(0)) {
+        // every trail climbs one step at a time, so each one to a peak is the same length
+        let trails = dijkstra_all(
+            trailhead,
+            |&pos| {
+                let next_height = map[pos].map(|height| height + 1);
+                map.neighbours4(pos)
+                    .filter(move |&next| map[next] == next_height)
+                    .map(|next| (next, 1))
+            },
+            |&pos| map[pos] == Some(9),
+        );
+
+        if let Some(trails) = trails {
+            total_score += trails.goals().len();
+            total_rating += trails.count();
+        }
+    }
+
+    (total_score, total_rating)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Input = (usize, usize);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(get_trailhead_scores_and_ratings(&parse_input(input)?))
+    }
+
+    fn part1((total_score, _): &Self::Input) -> Result<usize> {
+        Ok(*total_score)
+    }
+
+    fn part2((_, total_rating): &Self::Input) -> Result<usize> {
+        Ok(*total_rating)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "0123\n1234\n8765\n9876";
+    const EXAMPLE_2: &str = "...0...\n...1...\n...2...\n6543456\n7.....7\n8.....8\n9.....9";
+    const EXAMPLE_3: &str = "..90..9\n...1.98\n...2..7\n6543456\n765.987\n876....\n987....";
+    const EXAMPLE_4: &str = "10..9..\n2...8..\n3...7..\n4567654\n...8..3\n...9..2\n.....01";
+    const EXAMPLE_5: &str =
+        "89010123\n78121874\n87430965\n96549874\n45678903\n32019012\n01329801\n10456732";
+
+    const EXAMPLE_6: &str = ".....0.\n..4321.\n..5..2.\n..6543.\n..7..4.\n..8765.\n..9....";
+    const EXAMPLE_7: &str = "012345\n123456\n234567\n345678\n4.6789\n56789.";
+
+    #[test]
+    fn test_get_trailhead_scores() {
+        let scores = [EXAMPLE_1, EXAMPLE_2, EXAMPLE_3, EXAMPLE_4, EXAMPLE_5]
+            .iter()
+            .map(|input| get_trailhead_scores_and_ratings(&parse_input(input).unwrap()).0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(scores, vec![1, 2, 4, 3, 36]);
+    }
+
+    #[test]
+    fn test_get_trailhead_ratings() {
+        let scores = [EXAMPLE_6, EXAMPLE_3, EXAMPLE_7, EXAMPLE_5]
+            .iter()
+            .map(|input| get_trailhead_scores_and_ratings(&parse_input(input).unwrap()).1)
+            .collect::<Vec<_>>();
+
+        assert_eq!(scores, vec![3, 13, 227, 81]);
+    }
+}
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use aocutils::{Result, Solution};
+
 fn replace_stone(value: i64) -> (Option<i64>, Option<i64>) {
     if value == 0 {
         return (Some(1), None);
@@ -54,15 +56,30 @@ fn replace_all(values: Vec<i64>, n: usize) -> usize {
     counts.values().sum()
 }
 
-fn main() {
-    let input: Vec<i64> = aocutils::read_input("input")
-        .unwrap()
-        .split_whitespace()
-        .map(|n| n.parse().unwrap())
-        .collect();
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Input = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .split_whitespace()
+            .map(|n| n.parse().unwrap())
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(replace_all(input.clone(), 25))
+    }
 
-    println!("part 1: {}", replace_all(input.clone(), 25));
-    println!("part 2: {}", replace_all(input, 75));
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(replace_all(input.clone(), 75))
+    }
 }
 
 #[cfg(test)]
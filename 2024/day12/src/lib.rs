@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+
+use aocutils::{Grid, Result, Solution};
+
+type Coords = (usize, usize);
+
+fn parse_input(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input, |c| c)
+}
+
+fn find_plot(start: Coords, map: &Grid<char>, plant_type: &char, coords: &mut HashSet<Coords>) {
+    if &map[start] != plant_type {
+        return;
+    }
+
+    coords.insert(start);
+
+    for next in map.neighbours4(start) {
+        if coords.contains(&next) {
+            continue;
+        }
+
+        find_plot(next, map, plant_type, coords);
+    }
+}
+
+fn count_edges(region: &HashSet<Coords>, x_max: i32, y_max: i32) -> usize {
+    let mut sides = 0;
+
+    for plot in region {
+        for (xi, yi) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let x = plot.0 as i32 + xi;
+            let y = plot.1 as i32 + yi;
+
+            if x < 0
+                || x >= x_max
+                || y < 0
+                || y >= y_max
+                || !region.contains(&(x as usize, y as usize))
+            {
+                sides += 1;
+            }
+        }
+    }
+
+    sides
+}
+
+// every side of a region starts and ends at a corner, so counting corners gives the number of sides
+fn count_sides(region: &HashSet<Coords>) -> usize {
+    let contains = |(x, y): Coords, (dx, dy): (isize, isize)| match (
+        x.checked_add_signed(dx),
+        y.checked_add_signed(dy),
+    ) {
+        (Some(x), Some(y)) => region.contains(&(x, y)),
+        _ => false,
+    };
+
+    region
+        .iter()
+        .map(|&plot| {
+            [(-1, -1), (1, -1), (1, 1), (-1, 1)]
+                .into_iter()
+                .filter(|&(dx, dy)| {
+                    let horizontal = contains(plot, (dx, 0));
+                    let vertical = contains(plot, (0, dy));
+                    let diagonal = contains(plot, (dx, dy));
+
+                    // either an outside corner, or an inside corner where the region wraps around
+                    (!horizontal && !vertical) || (horizontal && vertical && !diagonal)
+                })
+                .count()
+        })
+        .sum()
+}
+
+fn find_all_plots(map: &Grid<char>) -> Vec<HashSet<Coords>> {
+    let mut plots = Vec::new();
+
+    for (pos, plant_type) in map.iter() {
+        if plots.iter().any(|v: &HashSet<Coords>| v.contains(&pos)) {
+            continue;
+        }
+
+        let mut plot_coords = HashSet::new();
+        find_plot(pos, map, plant_type, &mut plot_coords);
+
+        plots.push(plot_coords);
+    }
+
+    plots
+}
+
+fn calculate_price(region: &HashSet<Coords>, x_max: i32, y_max: i32, part2: bool) -> usize {
+    let edges = {
+        if !part2 {
+            count_edges(region, x_max, y_max)
+        } else {
+            count_sides(region)
+        }
+    };
+    edges * region.len()
+}
+
+fn calculate_price_all(map: &Grid<char>, part2: bool) -> usize {
+    find_all_plots(map)
+        .iter()
+        .map(|r| calculate_price(r, map.width() as i32, map.height() as i32, part2))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        Ok(calculate_price_all(map, false))
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        Ok(calculate_price_all(map, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE_1: &str = "AAAA\nBBCD\nBBCC\nEEEC";
+    static EXAMPLE_E: &str = "EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE";
+    static EXAMPLE_AB: &str = "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA";
+
+    #[test]
+    fn test_find_plot() {
+        let map = parse_input(EXAMPLE_1).unwrap();
+        let mut plot_coords = HashSet::new();
+        find_plot((0, 0), &map, &'A', &mut plot_coords);
+
+        assert_eq!(plot_coords, HashSet::from([(0, 0), (1, 0), (2, 0), (3, 0)]))
+    }
+
+    #[test]
+    fn test_find_all_plots() {
+        let map = parse_input(EXAMPLE_1).unwrap();
+        let plots = find_all_plots(&map);
+
+        assert_eq!(plots.len(), 5);
+    }
+
+    #[test]
+    fn test_count_edges() {
+        assert_eq!(
+            count_edges(&HashSet::from([(0, 0), (1, 0), (2, 0), (3, 0)]), 4, 4),
+            10
+        );
+    }
+
+    #[test]
+    fn test_count_sides() {
+        assert_eq!(
+            count_sides(&HashSet::from([(0, 0), (1, 0), (2, 0), (3, 0)])),
+            4
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let map = parse_input(EXAMPLE_1).unwrap();
+
+        assert_eq!(calculate_price_all(&map, false), 140);
+    }
+
+    #[test]
+    fn test_part2() {
+        let map = parse_input(EXAMPLE_1).unwrap();
+
+        assert_eq!(calculate_price_all(&map, true), 80);
+    }
+
+    #[test]
+    fn test_part2_inside_corners() {
+        assert_eq!(
+            calculate_price_all(&parse_input(EXAMPLE_E).unwrap(), true),
+            236
+        );
+        assert_eq!(
+            calculate_price_all(&parse_input(EXAMPLE_AB).unwrap(), true),
+            368
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use aocutils::{parse::Span, Result, Solution};
+
+const BUTTON_A_COST: i128 = 3;
+const BUTTON_B_COST: i128 = 1;
+
+/// How much further away every prize really is in part 2
+const PRIZE_OFFSET: i64 = 10_000_000_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Machine {
+    button_a: (i64, i64),
+    button_b: (i64, i64),
+    prize: (i64, i64),
+}
+
+// parse `<name>: X<sign><x>, Y<sign><y>`, where the sign is `+` for buttons and `=` for the prize
+fn parse_xy(line: Span, name: &str, sign: &str) -> Result<(i64, i64)> {
+    let (key, values) = line.key_value(":")?;
+    key.tag(name)?;
+    let (x, y) = values.split_once(", ")?;
+
+    Ok((
+        x.tag("X")?.tag(sign)?.number()?,
+        y.tag("Y")?.tag(sign)?.number()?,
+    ))
+}
+
+fn parse_machine(block: Span) -> Result<Machine> {
+    let [button_a, button_b, prize] = block.lines().collect::<Vec<_>>()[..] else {
+        return Err(block.error("expected two buttons and a prize"));
+    };
+
+    Ok(Machine {
+        button_a: parse_xy(button_a, "Button A", "+")?,
+        button_b: parse_xy(button_b, "Button B", "+")?,
+        prize: parse_xy(prize, "Prize", "=")?,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>> {
+    Span::new(input).map_blocks(parse_machine)
+}
+
+/// Returns `(g, x, y)` where `g` is the greatest common divisor of `a` and `b`, and `a * x + b * y = g`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+/// The cheapest `(a, b)` presses where `a * step_a + b * step_b = target`. Used when both buttons
+/// move in the same direction, so only the distance along it matters. Buttons only move forwards, so
+/// neither step can be negative.
+fn cheapest_on_line(step_a: i128, step_b: i128, target: i128) -> Option<(i128, i128)> {
+    let presses = |step: i128| (step != 0 && target % step == 0).then(|| target / step);
+
+    match (step_a, step_b) {
+        (0, 0) => return (target == 0).then_some((0, 0)),
+        (0, _) => return presses(step_b).map(|b| (0, b)),
+        (_, 0) => return presses(step_a).map(|a| (a, 0)),
+        _ => (),
+    }
+
+    let (g, x, y) = extended_gcd(step_a, step_b);
+    if target % g != 0 {
+        return None;
+    }
+
+    // every solution is `(a + k * da, b - k * db)` for some k, and only a range of k keeps both of
+    // them from going negative
+    let (a, b) = (x * (target / g), y * (target / g));
+    let (da, db) = (step_b / g, step_a / g);
+
+    let k_min = -a.div_euclid(da);
+    let k_max = b.div_euclid(db);
+    if k_min > k_max {
+        return None;
+    }
+
+    // the cost changes by the same amount for every step of k, so one end of the range is cheapest
+    let k = match BUTTON_A_COST * da > BUTTON_B_COST * db {
+        true => k_min,
+        false => k_max,
+    };
+
+    Some((a + k * da, b - k * db))
+}
+
+/// The cheapest way to win the prize, as `(cost, a presses, b presses)`
+fn cheapest_win(machine: &Machine) -> Option<(i128, i128, i128)> {
+    let (ax, ay) = (machine.button_a.0 as i128, machine.button_a.1 as i128);
+    let (bx, by) = (machine.button_b.0 as i128, machine.button_b.1 as i128);
+    let (px, py) = (machine.prize.0 as i128, machine.prize.1 as i128);
+
+    let determinant = ax * by - ay * bx;
+
+    let (a, b) = if determinant != 0 {
+        // there's exactly one way to reach the prize, found with Cramer's rule, but it only counts
+        // if it's a whole number of presses
+        let a = px * by - py * bx;
+        let b = ax * py - ay * px;
+
+        if a % determinant != 0 || b % determinant != 0 {
+            return None;
+        }
+        (a / determinant, b / determinant)
+    } else {
+        // the buttons move in the same direction, so the prize has to be on that line too
+        if ax * py - ay * px != 0 || bx * py - by * px != 0 {
+            return None;
+        }
+
+        match (ax, bx) {
+            (0, 0) => cheapest_on_line(ay, by, py)?,
+            _ => cheapest_on_line(ax, bx, px)?,
+        }
+    };
+
+    (a >= 0 && b >= 0).then_some((a * BUTTON_A_COST + b * BUTTON_B_COST, a, b))
+}
+
+fn total_cost(machines: &[Machine]) -> i128 {
+    machines
+        .iter()
+        .filter_map(cheapest_win)
+        .map(|(cost, _, _)| cost)
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    type Input = Vec<Machine>;
+    type Part1 = i128;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Self::Input) -> Result<i128> {
+        Ok(total_cost(machines))
+    }
+
+    fn part2(machines: &Self::Input) -> Result<i128> {
+        let machines = machines
+            .iter()
+            .map(|m| Machine {
+                prize: (m.prize.0 + PRIZE_OFFSET, m.prize.1 + PRIZE_OFFSET),
+                ..*m
+            })
+            .collect::<Vec<_>>();
+
+        Ok(total_cost(&machines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aocutils::Error;
+
+    aocutils::examples!(Day13, part1);
+
+    static EXAMPLE_INPUT: &str = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\nButton A: X+26, Y+66\nButton B: X+67, Y+21\nPrize: X=12748, Y=12176\n\nButton A: X+17, Y+86\nButton B: X+84, Y+37\nPrize: X=7870, Y=6450\n\nButton A: X+69, Y+23\nButton B: X+27, Y+71\nPrize: X=18641, Y=10279";
+
+    fn machine(button_a: (i64, i64), button_b: (i64, i64), prize: (i64, i64)) -> Machine {
+        Machine {
+            button_a,
+            button_b,
+            prize,
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            parse_input(EXAMPLE_INPUT).unwrap(),
+            Vec::from([
+                machine((94, 34), (22, 67), (8400, 5400)),
+                machine((26, 66), (67, 21), (12748, 12176)),
+                machine((17, 86), (84, 37), (7870, 6450)),
+                machine((69, 23), (27, 71), (18641, 10279)),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_input_error() {
+        let input = "Button A: X+94, Y+34\nButton B: X+22, Z+67\nPrize: X=8400, Y=5400";
+
+        match parse_input(input) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 17)),
+            res => panic!("expected a parse error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        let machines = parse_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(cheapest_win(&machines[0]), Some((280, 80, 40)));
+        assert_eq!(cheapest_win(&machines[1]), None);
+        assert_eq!(cheapest_win(&machines[2]), Some((200, 38, 86)));
+        assert_eq!(cheapest_win(&machines[3]), None);
+        assert_eq!(Day13::part1(&machines).unwrap(), 480);
+    }
+
+    #[test]
+    fn test_examples_part2() {
+        let machines = parse_input(EXAMPLE_INPUT).unwrap();
+
+        // only the second and fourth machines can be won once the prizes are further away
+        assert_eq!(Day13::part2(&machines).unwrap(), 875318608908);
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        // B is cheaper for the distance it moves
+        assert_eq!(
+            cheapest_win(&machine((2, 2), (1, 1), (10, 10))),
+            Some((10, 0, 10))
+        );
+        // A is cheaper, but can't get all the way on its own
+        assert_eq!(
+            cheapest_win(&machine((4, 4), (1, 1), (10, 10))),
+            Some((8, 2, 2))
+        );
+        assert_eq!(cheapest_win(&machine((6, 3), (4, 2), (2, 1))), None);
+        assert_eq!(
+            cheapest_win(&machine((6, 3), (4, 2), (22, 11))),
+            Some((7, 1, 4))
+        );
+        // the prize isn't on the line the buttons move along
+        assert_eq!(cheapest_win(&machine((2, 2), (1, 1), (10, 11))), None);
+        // only moving in y
+        assert_eq!(
+            cheapest_win(&machine((0, 3), (0, 5), (0, 11))),
+            Some((7, 2, 1))
+        );
+    }
+}
@@ -1,44 +1,34 @@
+use aocutils::{
+    parse::FromInput,
+    viz::{Animation, Color, Palette},
+    Error, Result, Solution,
+};
 use itertools::Itertools;
-use regex::Regex;
 
 type Coords = (isize, isize);
 
 static BOUNDARY: Coords = (101, 103);
 
-#[derive(Debug, PartialEq, Eq)]
-struct Robot {
+#[derive(Debug, PartialEq, Eq, Clone, FromInput)]
+#[aoc(pattern = "p={position.0},{position.1} v={velocity.0},{velocity.1}")]
+pub struct Robot {
     pub position: Coords,
     pub velocity: Coords,
 }
 
-fn parse_input(input: &str) -> Vec<Robot> {
-    let re = Regex::new(r"(?m)^p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)$").unwrap();
-
-    re.captures_iter(input)
-        .map(|c| {
-            let (_, [start_x, start_y, velocity_x, velocity_y]) = c.extract();
-            Robot {
-                position: (start_x.parse().unwrap(), start_y.parse().unwrap()),
-                velocity: (velocity_x.parse().unwrap(), velocity_y.parse().unwrap()),
-            }
-        })
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<Robot>> {
+    Robot::from_input(input)
 }
 
-fn gen_map(robots: &Vec<Robot>, boundary: Coords) -> String {
+pub fn gen_map(robots: &[Robot], boundary: Coords) -> String {
+    let counts = robots.iter().counts_by(|r| r.position);
     let mut map = String::new();
 
     for y in 0..boundary.1 {
         for x in 0..boundary.0 {
-            let robot_count = robots
-                .iter()
-                .filter(|r| r.position.0 == x && r.position.1 == y)
-                .count();
-
-            if robot_count > 0 {
-                map += &(robot_count % 10).to_string();
-            } else {
-                map += ".";
+            match counts.get(&(x, y)) {
+                Some(count) => map += &(count % 10).to_string(),
+                None => map += ".",
             }
         }
         map += "\n";
@@ -47,7 +37,7 @@ fn gen_map(robots: &Vec<Robot>, boundary: Coords) -> String {
     map.trim().to_string()
 }
 
-fn move_robots(robots: &mut Vec<Robot>, boundary: Coords) {
+fn move_robots(robots: &mut [Robot], boundary: Coords) {
     for r in robots.iter_mut() {
         r.position.0 = (r.position.0 + r.velocity.0) % boundary.0;
         r.position.1 = (r.position.1 + r.velocity.1) % boundary.1;
@@ -62,7 +52,7 @@ fn move_robots(robots: &mut Vec<Robot>, boundary: Coords) {
     }
 }
 
-fn count_robots(robots: &Vec<Robot>, boundary: Coords) -> (usize, usize, usize, usize) {
+fn count_robots(robots: &[Robot], boundary: Coords) -> (usize, usize, usize, usize) {
     let horizontal = boundary.0 / 2;
     let vertical = boundary.1 / 2;
 
@@ -107,84 +97,97 @@ fn count_robots(robots: &Vec<Robot>, boundary: Coords) -> (usize, usize, usize,
     )
 }
 
-/// Scan for lines with `threshold` items in a row
-fn scan_for_grouped(robots: &Vec<Robot>, boundary: Coords, i: usize, threshold: usize) -> bool {
-    for y in 0..boundary.1 {
-        // get all the robot x positions in this row
-        let mut x_positions = robots
-            .iter()
-            .filter(|r| r.position.1 == y)
-            .map(|r| r.position.0)
-            .unique()
-            .collect::<Vec<isize>>();
+/// One coordinate of where a robot is after `t` seconds, out of `size` along that axis
+fn position_at(position: isize, velocity: isize, t: isize, size: isize) -> isize {
+    (position + velocity * t).rem_euclid(size)
+}
 
-        if x_positions.len() == 0 {
-            continue;
-        }
+/// How spread out some positions are, as their variance multiplied by the number of positions
+/// squared, which keeps it a whole number without changing which is smallest
+fn spread(positions: impl Iterator<Item = isize>) -> isize {
+    let (count, sum, sum_squares) = positions.fold((0, 0, 0), |(count, sum, squares), p| {
+        (count + 1, sum + p, squares + p * p)
+    });
 
-        x_positions.sort();
+    count * sum_squares - sum * sum
+}
 
-        let mut max_len = 0;
-        let mut current_len = 0;
+/// The second, out of the `size` it takes for every robot to come back around along one axis,
+/// where the robots are most bunched up along it
+fn tightest_second(robots: &[Robot], size: isize, axis: impl Fn(Coords) -> isize) -> isize {
+    (0..size)
+        .min_by_key(|&t| {
+            spread(
+                robots
+                    .iter()
+                    .map(|r| position_at(axis(r.position), axis(r.velocity), t, size)),
+            )
+        })
+        .unwrap_or(0)
+}
 
-        for i in 0..(x_positions.len() - 1) {
-            // get the previous, current and next positions in the line
-            let prev = {
-                if i <= 0 {
-                    &-100
-                } else {
-                    &x_positions[i - 1]
-                }
-            };
-            let cur = &x_positions[i];
-            let next = &x_positions[i + 1];
+/// Find the second where the robots form a picture, which is when they're bunched up in both x and
+/// y at once.
+///
+/// Movement along each axis repeats on its own, every `boundary.0` seconds for x and `boundary.1`
+/// for y, so the second the robots are tightest along each one can be found separately. As long as
+/// the sizes are coprime, the Chinese remainder theorem then gives exactly one second in the
+/// combined period where both happen together.
+fn find_easter_egg(robots: &[Robot], boundary: Coords) -> Option<isize> {
+    let (width, height) = boundary;
+    let tx = tightest_second(robots, width, |(x, _)| x);
+    let ty = tightest_second(robots, height, |(_, y)| y);
+
+    // every second that's right for x, until one is right for y too
+    (0..height)
+        .map(|k| tx + k * width)
+        .find(|t| t % height == ty)
+}
 
-            // if the previous is not 1 position before the current, reset the current length counter
-            if cur - prev != 1 {
-                current_len = 0;
-            }
+pub struct Day14;
 
-            // if the next position is 1 in front, increment the current length
-            if next - cur == 1 {
-                current_len += 1;
-            }
+impl Solution for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
 
-            // if the current length is the highest, update the max length
-            if current_len > max_len {
-                max_len = current_len;
-            }
-        }
+    type Input = Vec<Robot>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-        // if this length is over the threshold, print the map for review
-        if max_len > threshold {
-            println!(
-                "-=-=-=-=-=-=-=- t={} -=-=-=-=-=-=-=-\n{}",
-                i,
-                gen_map(robots, boundary)
-            );
-            return true;
-        }
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
     }
 
-    false
-}
+    fn part1(robots: &Self::Input) -> Result<usize> {
+        let mut robots = robots.clone();
+        for _ in 0..100 {
+            move_robots(&mut robots, BOUNDARY);
+        }
 
-fn main() {
-    let mut robots = parse_input(&aocutils::read_input("input").unwrap());
-    for t in 0..100 {
-        move_robots(&mut robots, BOUNDARY);
-        scan_for_grouped(&robots, BOUNDARY, t, 10);
+        let (tl, tr, bl, br) = count_robots(&robots, BOUNDARY);
+        Ok(tl * tr * bl * br)
     }
 
-    let (tl, tr, bl, br) = count_robots(&robots, BOUNDARY);
-    println!("part 1: {}", tl * tr * bl * br);
+    fn part2(robots: &Self::Input) -> Result<usize> {
+        find_easter_egg(robots, BOUNDARY)
+            .map(|t| t as usize)
+            .ok_or_else(|| Error::invalid_state("the width and height of the room aren't coprime"))
+    }
 
-    let mut t = 100;
-    let mut found = false;
-    while !found {
-        t += 1;
-        move_robots(&mut robots, BOUNDARY);
-        found = scan_for_grouped(&robots, BOUNDARY, t, 10);
+    fn animate(robots: &Self::Input) -> Option<Animation> {
+        let mut robots = robots.clone();
+
+        // each frame is a second later, starting from 0, until every robot is back where it
+        // started
+        let period = BOUNDARY.0 * BOUNDARY.1;
+        let frames = (0..period).map(move |t| {
+            if t > 0 {
+                move_robots(&mut robots, BOUNDARY);
+            }
+            gen_map(&robots, BOUNDARY)
+        });
+
+        Some(Animation::new(frames).palette(Palette::new().with("123456789", Color::BrightGreen)))
     }
 }
 
@@ -198,7 +201,7 @@ mod tests {
     #[test]
     fn test_parse_input() {
         assert_eq!(
-            parse_input(EXAMPLE_INPUT),
+            parse_input(EXAMPLE_INPUT).unwrap(),
             Vec::from([
                 Robot {
                     position: (0, 4),
@@ -260,11 +263,11 @@ mod tests {
         }]);
         let expected_positions = Vec::from([(2, 4), (4, 1), (6, 5), (8, 2), (10, 6), (1, 3)]);
 
-        for t in 0..expected_positions.len() {
+        for (t, expected_pos) in expected_positions.into_iter().enumerate() {
             assert_eq!(
-                robots[0].position, expected_positions[t],
+                robots[0].position, expected_pos,
                 "expected position {:?} at t={}, got {:?}",
-                expected_positions[t], t, robots[0].position
+                expected_pos, t, robots[0].position
             );
             move_robots(&mut robots, EXAMPLE_BOUNDARY);
         }
@@ -302,7 +305,7 @@ mod tests {
 
     #[test]
     fn test_move_robots_100() {
-        let mut robots = parse_input(EXAMPLE_INPUT);
+        let mut robots = parse_input(EXAMPLE_INPUT).unwrap();
         for _ in 0..100 {
             move_robots(&mut robots, EXAMPLE_BOUNDARY);
         }
@@ -312,7 +315,7 @@ mod tests {
 
     #[test]
     fn test_count_robots() {
-        let mut robots = parse_input(EXAMPLE_INPUT);
+        let mut robots = parse_input(EXAMPLE_INPUT).unwrap();
         for _ in 0..100 {
             move_robots(&mut robots, EXAMPLE_BOUNDARY);
         }
@@ -323,8 +326,50 @@ mod tests {
     #[test]
     fn test_count_input_robots() {
         assert_eq!(
-            parse_input(&aocutils::read_input("input").unwrap()).len(),
+            parse_input(&aocutils::read_input!("input").unwrap())
+                .unwrap()
+                .len(),
             500
         );
     }
+
+    /// A room of robots moving about at random, apart from some that all meet up in a small square
+    /// after `t` seconds
+    fn synthetic_robots(t: isize, boundary: Coords) -> Vec<Robot> {
+        // a linear congruential generator is plenty random enough for this
+        let mut seed: u64 = 2024;
+        let mut random = move |n: isize| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % n as u64) as isize
+        };
+
+        (0..400)
+            .map(|i| {
+                let velocity = (random(201) - 100, random(201) - 100);
+                let position_at_t = match i < 150 {
+                    true => (40 + random(15), 50 + random(15)),
+                    false => (random(boundary.0), random(boundary.1)),
+                };
+
+                // wind it back to where it would have started
+                Robot {
+                    position: (
+                        (position_at_t.0 - velocity.0 * t).rem_euclid(boundary.0),
+                        (position_at_t.1 - velocity.1 * t).rem_euclid(boundary.1),
+                    ),
+                    velocity,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_find_easter_egg() {
+        for t in [0, 1, 100, 6243, 10402] {
+            let robots = synthetic_robots(t, BOUNDARY);
+            assert_eq!(find_easter_egg(&robots, BOUNDARY), Some(t), "for t={}", t);
+        }
+    }
 }
@@ -0,0 +1,499 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aocutils::{
+    viz::{Animation, Color, Palette},
+    Direction4, Error, Result, Solution,
+};
+
+type Coords = (isize, isize);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Puzzle {
+    robot: Coords,
+    map: HashMap<Coords, Item>,
+    movements: VecDeque<Direction4>,
+}
+
+impl Puzzle {
+    fn all_box_gps(&self) -> Vec<isize> {
+        self.map
+            .iter()
+            .filter(|(_, x)| x == &&Item::Box || x == &&Item::BigBoxLeft)
+            .map(|((x, y), _)| 100 * y + x)
+            .collect()
+    }
+
+    pub fn draw(&self) -> String {
+        let y_max = *self.map.iter().map(|((_, y), _)| y).max().unwrap() + 1;
+        let x_max = *self.map.iter().map(|((x, _), _)| x).max().unwrap() + 1;
+        let mut result = String::new();
+
+        for y in 0..y_max {
+            for x in 0..x_max {
+                result += match (self.robot == (x, y), self.map.get(&(x, y))) {
+                    (true, _) => "@",
+                    (_, Some(Item::Box)) => "O",
+                    (_, Some(Item::Wall)) => "#",
+                    (_, Some(Item::BigBoxLeft)) => "[",
+                    (_, Some(Item::BigBoxRight)) => "]",
+                    (_, None) => ".",
+                };
+            }
+            result += "\n";
+        }
+
+        result.trim().to_string()
+    }
+
+    /// Every item that would be pushed by moving into `start`, or `None` if any of them is blocked
+    /// by a wall. Pushing one half of a wide box pushes the other half too, so a vertical push can
+    /// spread out into a whole tree of boxes.
+    fn pushed_items(&self, start: Coords, dir: Direction4) -> Option<Vec<Coords>> {
+        let mut pushed = Vec::new();
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(pos) = queue.pop_front() {
+            if !seen.insert(pos) {
+                continue;
+            }
+
+            let item = match self.map.get(&pos) {
+                None => continue,
+                Some(Item::Wall) => return None,
+                Some(item) => item,
+            };
+
+            pushed.push(pos);
+            queue.push_back(step(dir, pos));
+
+            match item {
+                Item::BigBoxLeft => queue.push_back((pos.0 + 1, pos.1)),
+                Item::BigBoxRight => queue.push_back((pos.0 - 1, pos.1)),
+                _ => (),
+            }
+        }
+
+        Some(pushed)
+    }
+
+    /// Make the next movement, returning `false` once there are none left
+    fn move_robot(&mut self) -> bool {
+        let Some(dir) = self.movements.pop_front() else {
+            return false;
+        };
+
+        let new_pos = step(dir, self.robot);
+
+        // nothing moves unless everything in the way can, so a blocked push leaves the map as it was
+        let Some(pushed) = self.pushed_items(new_pos, dir) else {
+            return true;
+        };
+
+        // take everything out before putting it back, so that items don't overwrite each other
+        let items = pushed
+            .into_iter()
+            .map(|pos| (step(dir, pos), self.map.remove(&pos).unwrap()))
+            .collect::<Vec<_>>();
+        self.map.extend(items);
+        self.robot = new_pos;
+
+        true
+    }
+
+    /// The second warehouse, where everything except the robot is twice as wide
+    fn widen(&self) -> Puzzle {
+        let mut map = HashMap::new();
+
+        for (&(x, y), &item) in &self.map {
+            let (left, right) = match item {
+                Item::Box => (Item::BigBoxLeft, Item::BigBoxRight),
+                item => (item, item),
+            };
+            map.insert((x * 2, y), left);
+            map.insert((x * 2 + 1, y), right);
+        }
+
+        Puzzle {
+            robot: (self.robot.0 * 2, self.robot.1),
+            map,
+            movements: self.movements.clone(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Item {
+    Box,
+    Wall,
+    BigBoxLeft,
+    BigBoxRight,
+}
+
+impl Item {
+    fn from_char(value: char) -> Option<Self> {
+        match value {
+            '#' => Some(Item::Wall),
+            'O' => Some(Item::Box),
+            '[' => Some(Item::BigBoxLeft),
+            ']' => Some(Item::BigBoxRight),
+            _ => None,
+        }
+    }
+}
+
+fn step(dir: Direction4, pos: Coords) -> Coords {
+    dir.step(pos.into()).into()
+}
+
+fn parse_input(input: &str, double: bool) -> Result<Puzzle> {
+    let (map, movements) = input.split_once("\n\n").ok_or_else(|| {
+        Error::parse_at(
+            input,
+            &input[input.len()..],
+            "expected a blank line between the map and the movements",
+        )
+    })?;
+
+    let mut robot = None;
+    let mut items = HashMap::new();
+
+    for (y, line) in map.lines().enumerate() {
+        for (x, c) in line.char_indices() {
+            let pos = (x as isize, y as isize);
+            let text = &line[x..x + c.len_utf8()];
+
+            let item = match c {
+                '.' => continue,
+                '@' => {
+                    if robot.replace(pos).is_some() {
+                        return Err(Error::parse_at(input, text, "found a second robot"));
+                    }
+                    continue;
+                }
+                c => Item::from_char(c)
+                    .ok_or_else(|| Error::parse_at(input, text, "unexpected item"))?,
+            };
+
+            items.insert(pos, item);
+        }
+    }
+
+    let robot = robot.ok_or_else(|| Error::invalid_state("the map has no robot"))?;
+
+    let puzzle = Puzzle {
+        robot,
+        map: items,
+        movements: movements
+            .lines()
+            .flat_map(|line| {
+                line.char_indices().map(move |(x, c)| {
+                    Direction4::from_char(c).ok_or_else(|| {
+                        Error::parse_at(input, &line[x..x + c.len_utf8()], "unexpected direction")
+                    })
+                })
+            })
+            .collect::<Result<_>>()?,
+    };
+
+    Ok(if double { puzzle.widen() } else { puzzle })
+}
+
+/// The map before the robot moves and then after each of its movements
+fn moves(mut puzzle: Puzzle) -> impl Iterator<Item = String> {
+    let mut started = false;
+
+    std::iter::from_fn(move || {
+        if started && !puzzle.move_robot() {
+            return None;
+        }
+        started = true;
+
+        Some(puzzle.draw())
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    type Input = Puzzle;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input, false)
+    }
+
+    fn part1(puzzle: &Self::Input) -> Result<isize> {
+        let mut puzzle = puzzle.clone();
+        while puzzle.move_robot() {}
+
+        Ok(puzzle.all_box_gps().iter().sum())
+    }
+
+    fn part2(puzzle: &Self::Input) -> Result<isize> {
+        let mut puzzle = puzzle.widen();
+        while puzzle.move_robot() {}
+
+        Ok(puzzle.all_box_gps().iter().sum())
+    }
+
+    fn animate(puzzle: &Self::Input) -> Option<Animation> {
+        // the first warehouse and then the wide one
+        let frames = moves(puzzle.clone()).chain(moves(puzzle.widen()));
+
+        Some(
+            Animation::new(frames).fps(30.0).palette(
+                Palette::new()
+                    .with("#", Color::BrightBlack)
+                    .with("O[]", Color::Yellow)
+                    .with("@", Color::BrightGreen),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########\n\n<^^>>>vv<v>>v<<";
+    const DOUBLE_EXAMPLE: &str =
+        "#######\n#...#.#\n#.....#\n#..OO@#\n#..O..#\n#.....#\n#######\n\n<vv<<^^<<^^";
+
+    #[test]
+    fn test_parse_input() {
+        let puzzle = parse_input(SMALL_EXAMPLE, false).unwrap();
+
+        assert_eq!(puzzle.robot, (2, 2));
+        assert_eq!(
+            puzzle.movements,
+            VecDeque::from([
+                Direction4::West,
+                Direction4::North,
+                Direction4::North,
+                Direction4::East,
+                Direction4::East,
+                Direction4::East,
+                Direction4::South,
+                Direction4::South,
+                Direction4::West,
+                Direction4::South,
+                Direction4::East,
+                Direction4::East,
+                Direction4::South,
+                Direction4::West,
+                Direction4::West
+            ])
+        );
+        assert_eq!(puzzle.map.values().filter(|v| v == &&Item::Box).count(), 6);
+        assert_eq!(
+            puzzle.map.values().filter(|v| v == &&Item::Wall).count(),
+            30
+        );
+    }
+
+    #[test]
+    fn test_move_robot() {
+        let mut puzzle = parse_input(SMALL_EXAMPLE, false).unwrap();
+        let expected_positions: Vec<Coords> = Vec::from([
+            (2, 2),
+            (2, 2),
+            (2, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (4, 1),
+            (4, 2),
+            (4, 2),
+            (3, 2),
+            (3, 3),
+            (4, 3),
+            (5, 3),
+            (5, 4),
+            (4, 4),
+            (4, 4),
+        ]);
+
+        for (t, expected_pos) in expected_positions.into_iter().enumerate() {
+            println!("t={}\n{}\n\n", t, puzzle.draw());
+            assert_eq!(puzzle.robot, expected_pos, "bad position for t={}", t);
+            puzzle.move_robot();
+        }
+    }
+
+    #[test]
+    fn test_big_example() {
+        let mut puzzle =
+            parse_input(&aocutils::read_input!("big_example").unwrap(), false).unwrap();
+        while puzzle.move_robot() {}
+
+        assert_eq!(puzzle.draw(), "##########\n#.O.O.OOO#\n#........#\n#OO......#\n#OO@.....#\n#O#.....O#\n#O.....OO#\n#O.....OO#\n#OO....OO#\n##########");
+    }
+
+    #[test]
+    fn test_box_gps_example_3() {
+        let puzzle = Puzzle {
+            map: HashMap::from([
+                ((0, 0), Item::Wall),
+                ((1, 0), Item::Wall),
+                ((2, 0), Item::Wall),
+                ((3, 0), Item::Wall),
+                ((4, 0), Item::Wall),
+                ((5, 0), Item::Wall),
+                ((6, 0), Item::Wall),
+                ((0, 1), Item::Wall),
+                ((0, 1), Item::Wall),
+                ((0, 1), Item::Wall),
+                ((4, 1), Item::Box),
+            ]),
+            movements: VecDeque::new(),
+            robot: (0, 0),
+        };
+
+        assert_eq!(puzzle.all_box_gps(), vec![104]);
+    }
+
+    #[test]
+    fn test_box_gps_example_small() {
+        let mut puzzle = parse_input(SMALL_EXAMPLE, false).unwrap();
+        while puzzle.move_robot() {}
+
+        assert_eq!(puzzle.all_box_gps().iter().sum::<isize>(), 2028);
+    }
+
+    #[test]
+    fn test_box_gps_example_big() {
+        let mut puzzle =
+            parse_input(&aocutils::read_input!("big_example").unwrap(), false).unwrap();
+        while puzzle.move_robot() {}
+
+        assert_eq!(puzzle.all_box_gps().iter().sum::<isize>(), 10092);
+    }
+
+    #[test]
+    fn test_parse_example_big_double() {
+        let puzzle = parse_input(&aocutils::read_input!("big_example").unwrap(), true).unwrap();
+
+        assert_eq!(puzzle.draw(), "####################\n##....[]....[]..[]##\n##............[]..##\n##..[][]....[]..[]##\n##....[]@.....[]..##\n##[]##....[]......##\n##[]....[]....[]..##\n##..[][]..[]..[][]##\n##........[]......##\n####################");
+        assert_eq!(puzzle.robot, (8, 4));
+    }
+
+    #[test]
+    fn test_box_gps_example_double() {
+        let puzzle = Puzzle {
+            map: HashMap::from([
+                ((0, 0), Item::Wall),
+                ((1, 0), Item::Wall),
+                ((2, 0), Item::Wall),
+                ((3, 0), Item::Wall),
+                ((4, 0), Item::Wall),
+                ((5, 0), Item::Wall),
+                ((6, 0), Item::Wall),
+                ((7, 0), Item::Wall),
+                ((8, 0), Item::Wall),
+                ((9, 0), Item::Wall),
+                ((0, 1), Item::Wall),
+                ((1, 1), Item::Wall),
+                ((0, 2), Item::Wall),
+                ((1, 2), Item::Wall),
+                ((5, 1), Item::BigBoxLeft),
+                ((6, 1), Item::BigBoxRight),
+            ]),
+            movements: VecDeque::new(),
+            robot: (0, 0),
+        };
+
+        assert_eq!(puzzle.all_box_gps(), vec![105]);
+    }
+
+    #[test]
+    fn test_move_robot_double() {
+        let mut puzzle = parse_input(DOUBLE_EXAMPLE, true).unwrap();
+        let expected_positions: Vec<Coords> = Vec::from([
+            (10, 3),
+            (9, 3),
+            (9, 4),
+            (9, 5),
+            (8, 5),
+            (7, 5),
+            (7, 4),
+            (7, 4),
+            (6, 4),
+            (5, 4),
+            (5, 3),
+            (5, 2),
+        ]);
+
+        for (t, expected_pos) in expected_positions.into_iter().enumerate() {
+            println!("t={}\n{}\n\n", t, puzzle.draw());
+            assert_eq!(puzzle.robot, expected_pos, "bad position for t={}", t);
+            puzzle.move_robot();
+        }
+    }
+
+    #[test]
+    fn test_big_example_double() {
+        let mut puzzle = parse_input(&aocutils::read_input!("big_example").unwrap(), true).unwrap();
+        while puzzle.move_robot() {}
+
+        assert_eq!(puzzle.draw(), "####################\n##[].......[].[][]##\n##[]...........[].##\n##[]........[][][]##\n##[]......[]....[]##\n##..##......[]....##\n##..[]............##\n##..@......[].[][]##\n##......[][]..[]..##\n####################");
+    }
+
+    #[test]
+    fn test_box_gps_example_big_double() {
+        let puzzle = parse_input(&aocutils::read_input!("big_example").unwrap(), false).unwrap();
+
+        assert_eq!(Day15::part2(&puzzle).unwrap(), 9021);
+    }
+
+    #[test]
+    fn test_push_wide_box_tree() {
+        // the robot pushes one box up into two, and the left one of those is against a wall
+        let blocked = "##########\n##..##...#\n#...[][].#\n#....[]..#\n#....@...#\n##########";
+        let mut puzzle = parse_input(&format!("{}\n\n^", blocked), false).unwrap();
+        puzzle.move_robot();
+
+        assert_eq!(puzzle.draw(), blocked);
+
+        let open = "##########\n#........#\n#...[][].#\n#....[]..#\n#....@...#\n##########";
+        let mut puzzle = parse_input(&format!("{}\n\n^", open), false).unwrap();
+        puzzle.move_robot();
+
+        assert_eq!(
+            puzzle.draw(),
+            "##########\n#...[][].#\n#....[]..#\n#....@...#\n#........#\n##########"
+        );
+    }
+
+    #[test]
+    fn test_parse_unexpected_item() {
+        match parse_input("####\n#@x#\n####\n\n<>", false) {
+            Err(Error::Parse {
+                line, column, text, ..
+            }) => assert_eq!((line, column, text.as_str()), (2, 3, "x")),
+            r => panic!("expected a parse error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_parse_unexpected_direction() {
+        match parse_input("####\n#@.#\n####\n\n<>\n^?", false) {
+            Err(Error::Parse {
+                line, column, text, ..
+            }) => assert_eq!((line, column, text.as_str()), (6, 2, "?")),
+            r => panic!("expected a parse error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_parse_no_robot() {
+        assert!(matches!(
+            parse_input("####\n#..#\n####\n\n<>", false),
+            Err(Error::InvalidState(_))
+        ));
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use aocutils::{dijkstra, dijkstra_all, Direction4, Error, Grid, Point, Result, Solution};
+
+static TURN_COST: usize = 1000;
+static MOVE_COST: usize = 1;
+
+type State = (Point, Direction4);
+
+pub struct Maze {
+    start: Point,
+    goal: Point,
+    walls: Grid<bool>,
+}
+
+impl Maze {
+    fn successors(&self, &(pos, direction): &State) -> Vec<(State, usize)> {
+        let mut next = vec![
+            ((pos, direction.rotate_cw()), TURN_COST),
+            ((pos, direction.rotate_ccw()), TURN_COST),
+        ];
+
+        let ahead = direction.step(pos);
+        if self.walls.get_point(ahead) == Some(&false) {
+            next.push(((ahead, direction), MOVE_COST));
+        }
+
+        next
+    }
+
+    fn no_path() -> Error {
+        Error::invalid_state("there is no path through the maze")
+    }
+
+    fn best_path_cost(&self) -> Result<usize> {
+        let (_, cost) = dijkstra(
+            (self.start, Direction4::East),
+            |state| self.successors(state),
+            |&(pos, _)| pos == self.goal,
+        )
+        .ok_or_else(Maze::no_path)?;
+
+        Ok(cost)
+    }
+
+    /// The number of tiles that are on any of the best paths through the maze
+    fn best_path_tiles(&self) -> Result<usize> {
+        let paths = dijkstra_all(
+            (self.start, Direction4::East),
+            |state| self.successors(state),
+            |&(pos, _)| pos == self.goal,
+        )
+        .ok_or_else(Maze::no_path)?;
+
+        let tiles = paths
+            .nodes()
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .collect::<HashSet<Point>>();
+
+        Ok(tiles.len())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Maze> {
+    let map = Grid::try_parse(input, |c| match c {
+        '#' | '.' | 'S' | 'E' => Some(c),
+        _ => None,
+    })?;
+
+    let find = |target: char| {
+        map.find(|&c| c == target)
+            .and_then(Point::from_usize)
+            .ok_or_else(|| Error::invalid_state(format!("the maze has no {}", target)))
+    };
+
+    Ok(Maze {
+        start: find('S')?,
+        goal: find('E')?,
+        walls: map.map(|&c| c == '#'),
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    type Input = Maze;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(maze: &Self::Input) -> Result<usize> {
+        maze.best_path_cost()
+    }
+
+    fn part2(maze: &Self::Input) -> Result<usize> {
+        maze.best_path_tiles()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE_1: &str = "###############\n#.......#....E#\n#.#.###.#.###.#\n#.....#.#...#.#\n#.###.#####.#.#\n#.#.#.......#.#\n#.#.#####.###.#\n#...........#.#\n###.#.#####.#.#\n#...#.....#.#.#\n#.#.#.###.#.#.#\n#.....#...#.#.#\n#.###.#.#.#.#.#\n#S..#.....#...#\n###############";
+    static EXAMPLE_2: &str = "#################\n#...#...#...#..E#\n#.#.#.#.#.#.#.#.#\n#.#.#.#...#...#.#\n#.#.#.#.###.#.#.#\n#...#.#.#.....#.#\n#.#.#.#.#.#####.#\n#.#...#.#.#.....#\n#.#.#####.#.###.#\n#.#.#.......#...#\n#.#.###.#####.###\n#.#.#...#.....#.#\n#.#.#.#####.###.#\n#.#.#.........#.#\n#.#.#.#########.#\n#S#.............#\n#################";
+
+    #[test]
+    fn test_example_1() {
+        let maze = parse_input(EXAMPLE_1).unwrap();
+        assert_eq!(maze.best_path_cost().unwrap(), 7036);
+        assert_eq!(maze.best_path_tiles().unwrap(), 45);
+    }
+
+    #[test]
+    fn test_example_2() {
+        let maze = parse_input(EXAMPLE_2).unwrap();
+        assert_eq!(maze.best_path_cost().unwrap(), 11048);
+        assert_eq!(maze.best_path_tiles().unwrap(), 64);
+    }
+
+    #[test]
+    fn test_no_path() {
+        let maze = parse_input("#####\n#S#E#\n#####").unwrap();
+        assert!(matches!(maze.best_path_cost(), Err(Error::InvalidState(_))));
+        assert!(matches!(
+            maze.best_path_tiles(),
+            Err(Error::InvalidState(_))
+        ));
+    }
+}
@@ -0,0 +1,778 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs::File,
+    io::{self, BufWriter, Write},
+    ops::BitXor,
+    path::{Path, PathBuf},
+};
+
+use aocutils::{parse::Span, Error, Result, Solution};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Computer {
+    memory: Vec<u8>,
+    instruction_pointer: usize,
+    register_a: i64,
+    register_b: i64,
+    register_c: i64,
+    output: Vec<i64>,
+}
+
+impl Computer {
+    fn load_program(input: &str) -> Result<Self> {
+        let (registers, program) = Span::new(input).split_once("\n\n")?;
+
+        let [a, b, c] = registers.lines().collect::<Vec<_>>()[..] else {
+            return Err(registers.error("expected registers A, B and C"));
+        };
+        let register = |line: Span, name: &str| -> Result<i64> {
+            line.tag("Register ")?.tag(name)?.tag(": ")?.number()
+        };
+
+        let memory = program.tag("Program: ")?.list(",", |n| {
+            let value = n.number::<u8>()?;
+            match value {
+                0..=7 => Ok(value),
+                _ => Err(n.error("expected a 3-bit number")),
+            }
+        })?;
+
+        Ok(Computer {
+            memory,
+            register_a: register(a, "A")?,
+            register_b: register(b, "B")?,
+            register_c: register(c, "C")?,
+            instruction_pointer: 0,
+            output: Vec::new(),
+        })
+    }
+
+    fn combo_operand(&self, operand: u8) -> Result<i64> {
+        match operand {
+            0..=3 => Ok(operand as i64),
+            4 => Ok(self.register_a),
+            5 => Ok(self.register_b),
+            6 => Ok(self.register_c),
+            _ => Err(Error::invalid_state(format!(
+                "combo operand {} at {} is reserved",
+                operand, self.instruction_pointer
+            ))),
+        }
+    }
+
+    /// Register A divided by 2 to the power of the combo operand, as used by adv, bdv and cdv
+    fn divide_a(&self, operand: u8) -> Result<i64> {
+        let power = self.combo_operand(operand)?;
+        let power = u32::try_from(power).map_err(|_| {
+            Error::invalid_state(format!("can't divide A by 2 to the power of {}", power))
+        })?;
+
+        // anything past 2^62 is bigger than any register, so there's nothing left
+        Ok(2_i64.checked_pow(power).map_or(0, |d| self.register_a / d))
+    }
+
+    /// Run one instruction, returning whether the program has halted
+    fn cycle(&mut self) -> Result<bool> {
+        if self.instruction_pointer + 1 >= self.memory.len() {
+            return Ok(true);
+        }
+
+        let opcode = self.memory[self.instruction_pointer];
+        let operand = self.memory[self.instruction_pointer + 1];
+
+        match opcode {
+            // adv
+            0 => self.register_a = self.divide_a(operand)?,
+            // bxl
+            1 => self.register_b = self.register_b.bitxor(operand as i64),
+            // bst
+            2 => self.register_b = self.combo_operand(operand)? % 8,
+            // jnz
+            3 => {
+                if self.register_a != 0 {
+                    self.instruction_pointer = operand as usize;
+                    return Ok(false);
+                }
+            }
+            // bxc
+            4 => self.register_b = self.register_b.bitxor(self.register_c),
+            // out
+            5 => self.output.push(self.combo_operand(operand)? % 8),
+            // bdv
+            6 => self.register_b = self.divide_a(operand)?,
+            // cdv
+            7 => self.register_c = self.divide_a(operand)?,
+            _ => {
+                return Err(Error::invalid_state(format!(
+                    "unexpected opcode {} at {}",
+                    opcode, self.instruction_pointer
+                )))
+            }
+        }
+
+        self.instruction_pointer += 2;
+
+        Ok(false)
+    }
+
+    /// The program as one instruction per line, with its address, and combo operands shown as the
+    /// number or register they stand for
+    pub fn disassemble(&self) -> String {
+        self.memory
+            .chunks(2)
+            .enumerate()
+            .map(|(i, instruction)| {
+                let operand = instruction.get(1).copied().unwrap_or(0);
+                format!("{:2}: {}", i * 2, mnemonic(instruction[0], operand))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn state(&self) -> State {
+        State {
+            instruction_pointer: self.instruction_pointer,
+            register_a: self.register_a,
+            register_b: self.register_b,
+            register_c: self.register_c,
+        }
+    }
+
+    /// Run until the program halts, or fail if it's still going after `cycle_limit` instructions
+    fn run(&mut self, cycle_limit: usize) -> Result<()> {
+        for _ in 0..cycle_limit {
+            if self.cycle()? {
+                return Ok(());
+            }
+        }
+
+        Err(too_many_cycles(cycle_limit))
+    }
+
+    /// Run the program from the start with `register_a` in place of the original value
+    fn run_with(&self, register_a: i64) -> Result<Vec<i64>> {
+        let mut comp = Computer {
+            register_a,
+            instruction_pointer: 0,
+            output: Vec::new(),
+            ..self.clone()
+        };
+        comp.run(CYCLE_LIMIT)?;
+
+        Ok(comp.output)
+    }
+
+    /// The smallest value of register A that makes the program output a copy of itself.
+    ///
+    /// Like every puzzle input, the program has to be a loop that outputs something based on the
+    /// low bits of A and then shifts A right by 3. That means the last output only depends on the
+    /// top 3 bits of A, the one before it on the top 6 and so on, so A can be found by working
+    /// backwards through the program 3 bits at a time.
+    fn find_quine(&self) -> Result<Option<i64>> {
+        self.find_quine_from(0, self.memory.len())
+    }
+
+    /// Try each next 3 bits of A that would output the last `remaining` values of the program
+    fn find_quine_from(&self, register_a: i64, remaining: usize) -> Result<Option<i64>> {
+        if remaining == 0 {
+            return Ok(Some(register_a));
+        }
+
+        let expected = &self.memory[remaining - 1..];
+
+        // trying the lowest bits first means the first answer found is the smallest
+        for a in (0..8).map(|bits| register_a * 8 + bits) {
+            let output = self.run_with(a)?;
+            if !output.into_iter().eq(expected.iter().map(|&n| n as i64)) {
+                continue;
+            }
+
+            if let Some(quine) = self.find_quine_from(a, remaining - 1)? {
+                return Ok(Some(quine));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Far more instructions than any puzzle input runs, so hitting it means the program is stuck in
+/// a loop
+const CYCLE_LIMIT: usize = 1_000_000;
+
+fn too_many_cycles(cycle_limit: usize) -> Error {
+    Error::invalid_state(format!(
+        "the program was still running after {} instructions",
+        cycle_limit
+    ))
+}
+
+/// An instruction, with combo operands shown as the number or register they stand for
+fn mnemonic(opcode: u8, operand: u8) -> String {
+    let combo = || match operand {
+        0..=3 => operand.to_string(),
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        _ => format!("?{}", operand),
+    };
+
+    match opcode {
+        0 => format!("adv {}", combo()),
+        1 => format!("bxl {}", operand),
+        2 => format!("bst {}", combo()),
+        3 => format!("jnz {}", operand),
+        4 => "bxc".to_string(),
+        5 => format!("out {}", combo()),
+        6 => format!("bdv {}", combo()),
+        7 => format!("cdv {}", combo()),
+        _ => format!("??? {}", operand),
+    }
+}
+
+/// The registers and instruction pointer at one point in a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    pub instruction_pointer: usize,
+    pub register_a: i64,
+    pub register_b: i64,
+    pub register_c: i64,
+}
+
+/// One instruction run by the [`Debugger`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub opcode: u8,
+    pub operand: u8,
+    pub before: State,
+    pub after: State,
+    pub output: Option<i64>,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (before, after) = (self.before, self.after);
+
+        write!(
+            f,
+            "{:2}: {:<7} ip {} -> {}, A {} -> {}, B {} -> {}, C {} -> {}",
+            before.instruction_pointer,
+            mnemonic(self.opcode, self.operand),
+            before.instruction_pointer,
+            after.instruction_pointer,
+            before.register_a,
+            after.register_a,
+            before.register_b,
+            after.register_b,
+            before.register_c,
+            after.register_c,
+        )?;
+
+        match self.output {
+            Some(output) => write!(f, ", out {}", output),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Somewhere for the [`Debugger`] to stop, before running an instruction
+#[derive(Debug, Clone, Copy)]
+pub enum Breakpoint {
+    /// The instruction at this address is next
+    Address(usize),
+    /// Register A has this value
+    RegisterA(i64),
+    RegisterB(i64),
+    RegisterC(i64),
+    /// Anything else about the registers
+    When(fn(&State) -> bool),
+}
+
+impl Breakpoint {
+    fn hit(&self, state: &State) -> bool {
+        match *self {
+            Breakpoint::Address(address) => state.instruction_pointer == address,
+            Breakpoint::RegisterA(value) => state.register_a == value,
+            Breakpoint::RegisterB(value) => state.register_b == value,
+            Breakpoint::RegisterC(value) => state.register_c == value,
+            Breakpoint::When(condition) => condition(state),
+        }
+    }
+}
+
+/// Why the [`Debugger`] stopped running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    /// One of the breakpoints was hit, this is its index in the order they were added
+    Breakpoint(usize),
+}
+
+/// Runs a [`Computer`] an instruction at a time, recording a trace of everything it does
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    computer: Computer,
+    breakpoints: Vec<Breakpoint>,
+    cycle_limit: usize,
+    trace: Vec<Step>,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer) -> Self {
+        Debugger {
+            computer,
+            breakpoints: Vec::new(),
+            cycle_limit: CYCLE_LIMIT,
+            trace: Vec::new(),
+        }
+    }
+
+    pub fn breakpoint(mut self, breakpoint: Breakpoint) -> Self {
+        self.breakpoints.push(breakpoint);
+        self
+    }
+
+    /// How many instructions can be run before it's treated as an infinite loop, a million by
+    /// default
+    pub fn cycle_limit(mut self, cycle_limit: usize) -> Self {
+        self.cycle_limit = cycle_limit;
+        self
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    pub fn trace(&self) -> &[Step] {
+        &self.trace
+    }
+
+    /// Run the next instruction, returning `None` if the program has already halted
+    pub fn step(&mut self) -> Result<Option<&Step>> {
+        if self.trace.len() >= self.cycle_limit {
+            return Err(too_many_cycles(self.cycle_limit));
+        }
+
+        let comp = &mut self.computer;
+        let before = comp.state();
+        let outputs = comp.output.len();
+
+        let Some(&[opcode, operand]) = comp
+            .memory
+            .get(before.instruction_pointer..before.instruction_pointer + 2)
+        else {
+            return Ok(None);
+        };
+
+        comp.cycle()?;
+        self.trace.push(Step {
+            opcode,
+            operand,
+            before,
+            after: comp.state(),
+            output: comp.output.get(outputs).copied(),
+        });
+
+        Ok(self.trace.last())
+    }
+
+    /// Run until the program halts or a breakpoint is hit. It always runs at least one
+    /// instruction, so that running again carries on past the breakpoint.
+    pub fn run(&mut self) -> Result<Stop> {
+        if self.step()?.is_none() {
+            return Ok(Stop::Halted);
+        }
+
+        loop {
+            let state = self.computer.state();
+            if let Some(i) = self.breakpoints.iter().position(|b| b.hit(&state)) {
+                return Ok(Stop::Breakpoint(i));
+            }
+
+            if self.step()?.is_none() {
+                return Ok(Stop::Halted);
+            }
+        }
+    }
+
+    /// Write the trace so far, one instruction per line
+    pub fn write_trace(&self, mut out: impl Write) -> io::Result<()> {
+        for step in &self.trace {
+            writeln!(out, "{}", step)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn save_trace(&self, path: &Path) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_trace(&mut out)?;
+        out.flush()
+    }
+}
+
+/// Where `aoc explain` saves the trace of a run
+fn trace_path() -> PathBuf {
+    env::temp_dir().join("aoc-2024-day17-trace.txt")
+}
+
+/// Run the program in the [`Debugger`], stopping before each `out` to show the registers, and
+/// save the full trace to `path`. The trace is saved even if the program goes wrong, so that it
+/// can be looked through to see why.
+fn explain_run(comp: &Computer, path: &Path) -> Result<String> {
+    let mut debugger = comp
+        .memory
+        .chunks(2)
+        .enumerate()
+        .filter(|(_, instruction)| instruction[0] == 5)
+        .fold(Debugger::new(comp.clone()), |debugger, (i, _)| {
+            debugger.breakpoint(Breakpoint::Address(i * 2))
+        });
+
+    let mut lines = Vec::new();
+    let result = loop {
+        match debugger.run() {
+            Ok(Stop::Breakpoint(_)) => {
+                let state = debugger.computer().state();
+                lines.push(format!(
+                    "{:2}: A {}, B {}, C {}",
+                    state.instruction_pointer, state.register_a, state.register_b, state.register_c
+                ));
+            }
+            Ok(Stop::Halted) => break Ok(()),
+            Err(e) => break Err(e),
+        }
+    };
+
+    debugger.save_trace(path).map_err(|e| {
+        Error::invalid_state(format!(
+            "couldn't save the trace to {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+    lines.push(format!(
+        "{} instructions traced to {}",
+        debugger.trace().len(),
+        path.display()
+    ));
+    if let Err(e) = result {
+        lines.push(format!("stopped early: {}", e));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 17;
+
+    type Input = Computer;
+    type Part1 = String;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Computer::load_program(input)
+    }
+
+    fn part1(comp: &Self::Input) -> Result<String> {
+        let mut comp = comp.clone();
+        comp.run(CYCLE_LIMIT)?;
+
+        Ok(comp
+            .output
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+
+    fn part2(comp: &Self::Input) -> Result<i64> {
+        comp.find_quine()?.ok_or_else(|| {
+            Error::invalid_state("no value of register A makes the program output itself")
+        })
+    }
+
+    fn explain(comp: &Self::Input) -> Result<Option<String>> {
+        Ok(Some(format!(
+            "program:\n{}\n\nregisters before each output:\n{}",
+            comp.disassemble(),
+            explain_run(comp, &trace_path())?
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    aocutils::examples!(Day17);
+
+    static EXAMPLE_INPUT: &str =
+        "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0";
+    static QUINE_INPUT: &str =
+        "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0";
+
+    fn test_program(program: &[u8], register_a: i64, register_b: i64, register_c: i64) -> Computer {
+        let mut comp = Computer {
+            instruction_pointer: 0,
+            memory: program.to_vec(),
+            register_a,
+            register_b,
+            register_c,
+            output: Vec::new(),
+        };
+        while !comp.cycle().unwrap() {}
+        comp
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            Computer::load_program(EXAMPLE_INPUT).unwrap(),
+            Computer {
+                memory: vec![0, 1, 5, 4, 3, 0],
+                instruction_pointer: 0,
+                register_a: 729,
+                register_b: 0,
+                register_c: 0,
+                output: vec![]
+            }
+        );
+    }
+
+    #[test]
+    fn test_program_1() {
+        let comp = test_program(&[2, 6], 0, 0, 9);
+        assert_eq!(comp.register_b, 1);
+    }
+
+    #[test]
+    fn test_program_2() {
+        let comp = test_program(&[5, 0, 5, 1, 5, 4], 10, 0, 0);
+        assert_eq!(comp.output, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_program_3() {
+        let comp = test_program(&[0, 1, 5, 4, 3, 0], 2024, 0, 0);
+        assert_eq!(comp.register_a, 0);
+        assert_eq!(comp.output, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+    }
+
+    #[test]
+    fn test_program_4() {
+        let comp = test_program(&[1, 7], 0, 29, 0);
+        assert_eq!(comp.register_b, 26);
+    }
+
+    #[test]
+    fn test_program_5() {
+        let comp = test_program(&[4, 0], 0, 2024, 43690);
+        assert_eq!(comp.register_b, 44354);
+    }
+
+    #[test]
+    fn test_program_example() {
+        let mut comp = Computer::load_program(EXAMPLE_INPUT).unwrap();
+        while !comp.cycle().unwrap() {}
+        assert_eq!(comp.output, vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let comp = Computer::load_program(QUINE_INPUT).unwrap();
+        assert_eq!(comp.disassemble(), " 0: adv 3\n 2: out A\n 4: jnz 0");
+
+        let comp = test_program(&[2, 4, 1, 1, 7, 5, 4, 0, 6, 6], 0, 0, 0);
+        assert_eq!(
+            comp.disassemble(),
+            " 0: bst A\n 2: bxl 1\n 4: cdv B\n 6: bxc\n 8: bdv C"
+        );
+    }
+
+    // somewhere for a test to save a trace, which is only used by that test
+    fn scratch_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!(
+            "aoc-2024-day17-{}-{}.txt",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_explain() {
+        let comp = Computer::load_program(QUINE_INPUT).unwrap();
+        let explanation = Day17::explain(&comp).unwrap().unwrap();
+
+        assert!(explanation.starts_with(
+            "program:\n 0: adv 3\n 2: out A\n 4: jnz 0\n\nregisters before each output:\n 2: A 253, B 0, C 0\n"
+        ));
+        assert!(explanation.ends_with(&format!(
+            "12 instructions traced to {}",
+            trace_path().display()
+        )));
+    }
+
+    #[test]
+    fn test_explain_run_stopped_early() {
+        let path = scratch_path("stopped-early");
+        let comp = Computer::load_program(
+            "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,1,5,7",
+        )
+        .unwrap();
+        let explanation = explain_run(&comp, &path).unwrap();
+
+        assert_eq!(
+            explanation,
+            format!(
+                " 2: A 1, B 1, C 0\n1 instructions traced to {}\nstopped early: invalid puzzle state: combo operand 7 at 2 is reserved",
+                path.display()
+            )
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 1);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_save_trace() {
+        let path = scratch_path("save-trace");
+        let mut debugger = Debugger::new(Computer::load_program(EXAMPLE_INPUT).unwrap());
+        debugger.run().unwrap();
+        debugger.save_trace(&path).unwrap();
+
+        let saved = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            saved.lines().collect::<Vec<_>>(),
+            debugger
+                .trace()
+                .iter()
+                .map(|step| step.to_string())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_find_quine_example() {
+        let comp = Computer::load_program(QUINE_INPUT).unwrap();
+        assert_eq!(comp.find_quine().unwrap(), Some(117440));
+        assert_eq!(comp.run_with(117440).unwrap(), vec![0, 3, 5, 4, 3, 0]);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut debugger = Debugger::new(Computer::load_program(EXAMPLE_INPUT).unwrap());
+        assert_eq!(debugger.run().unwrap(), Stop::Halted);
+
+        // three instructions a loop, for each of the ten outputs
+        let trace = debugger.trace();
+        assert_eq!(trace.len(), 30);
+        assert_eq!(
+            trace[0].to_string(),
+            " 0: adv 1   ip 0 -> 2, A 729 -> 364, B 0 -> 0, C 0 -> 0"
+        );
+        assert_eq!(
+            trace[1].to_string(),
+            " 2: out A   ip 2 -> 4, A 364 -> 364, B 0 -> 0, C 0 -> 0, out 4"
+        );
+        assert_eq!(trace[29].after.instruction_pointer, 6);
+        assert_eq!(debugger.step().unwrap(), None);
+
+        let mut dump = Vec::new();
+        debugger.write_trace(&mut dump).unwrap();
+        assert_eq!(String::from_utf8(dump).unwrap().lines().count(), 30);
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut debugger = Debugger::new(Computer::load_program(EXAMPLE_INPUT).unwrap())
+            .breakpoint(Breakpoint::RegisterA(45))
+            .breakpoint(Breakpoint::Address(4))
+            .breakpoint(Breakpoint::When(|state| state.register_a < 10));
+
+        assert_eq!(debugger.run().unwrap(), Stop::Breakpoint(1));
+        assert_eq!(debugger.computer().output, vec![4]);
+
+        // carries on past the address it stopped at
+        assert_eq!(debugger.run().unwrap(), Stop::Breakpoint(1));
+        assert_eq!(debugger.computer().output, vec![4, 6]);
+
+        while debugger.run().unwrap() == Stop::Breakpoint(1) {}
+        assert_eq!(debugger.computer().register_a, 45);
+
+        let mut stops = Vec::new();
+        loop {
+            match debugger.run().unwrap() {
+                Stop::Halted => break,
+                stop => stops.push(stop),
+            }
+        }
+        assert!(stops.contains(&Stop::Breakpoint(2)));
+    }
+
+    #[test]
+    fn test_cycle_limit() {
+        // jumps back to the start forever, because nothing changes A
+        let comp = Computer {
+            memory: vec![1, 1, 3, 0],
+            instruction_pointer: 0,
+            register_a: 1,
+            register_b: 0,
+            register_c: 0,
+            output: Vec::new(),
+        };
+
+        let mut debugger = Debugger::new(comp.clone()).cycle_limit(100);
+        assert!(matches!(debugger.run(), Err(Error::InvalidState(_))));
+        assert_eq!(debugger.trace().len(), 100);
+
+        assert!(Day17::part1(&comp).is_err());
+    }
+
+    #[test]
+    fn test_find_quine_impossible() {
+        // the first output is always 7, never the 1 the program starts with
+        let comp = test_program(&[1, 7, 5, 5, 0, 3, 3, 0], 0, 0, 0);
+        assert_eq!(comp.find_quine().unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let errors = [
+            ("Register A: 729\nRegister B: 0\n\nProgram: 0,1", (1, 1)),
+            (
+                "Register A: 729\nRegister B: 0\nRegister D: 0\n\nProgram: 0,1",
+                (3, 10),
+            ),
+            (
+                "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8,3,0",
+                (5, 12),
+            ),
+            ("Register A: 729\nRegister B: 0\nRegister C: 0", (1, 1)),
+        ];
+
+        for (input, position) in errors {
+            match Computer::load_program(input) {
+                Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), position),
+                r => panic!("expected a parse error for {:?}, got {:?}", input, r),
+            }
+        }
+    }
+
+    #[test]
+    fn test_reserved_combo_operand() {
+        let mut comp =
+            Computer::load_program("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,7")
+                .unwrap();
+
+        assert!(matches!(comp.cycle(), Err(Error::InvalidState(_))));
+        assert!(Day17::part1(&comp).is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use aocutils::{
+    bfs,
+    viz::{Animation, Color, Palette},
+    Grid, Result, Solution,
+};
+
+static GRID_SIZE: usize = 70;
+
+type Coords = (usize, usize);
+
+fn parse_input(input: &str) -> Vec<Coords> {
+    input
+        .lines()
+        .map(|line| {
+            let (a, b) = line.split_once(",").unwrap();
+            (a.parse().unwrap(), b.parse().unwrap())
+        })
+        .collect()
+}
+
+fn corrupt_grid(coords: &[Coords], grid_size: usize) -> Grid<bool> {
+    let mut grid = Grid::new(grid_size + 1, grid_size + 1, false);
+
+    for &pos in coords {
+        grid[pos] = true;
+    }
+
+    grid
+}
+
+fn shortest_path(grid: &Grid<bool>, grid_size: usize) -> Option<Vec<Coords>> {
+    let (path, _) = bfs(
+        (0, 0),
+        |&pos| grid.neighbours4(pos).filter(|&next| !grid[next]),
+        |&pos| pos == (grid_size, grid_size),
+    )?;
+
+    Some(path)
+}
+
+fn find_path(grid: &Grid<bool>, grid_size: usize) -> Option<usize> {
+    shortest_path(grid, grid_size).map(|path| path.len() - 1)
+}
+
+/// Draw the corrupted memory, with `O` along `path`
+pub fn draw_map(grid: &Grid<bool>, path: &[Coords]) -> String {
+    let mut drawing = grid.map(|&corrupted| if corrupted { '#' } else { '.' });
+    for &pos in path {
+        drawing[pos] = 'O';
+    }
+
+    drawing.to_string()
+}
+
+fn find_blocking_coord(coords: &[Coords], grid_size: usize) -> Option<Coords> {
+    let mut start = 0;
+    let mut end = coords.len();
+
+    while start != end {
+        let mid = start + ((end - start) / 2);
+
+        let grid = corrupt_grid(&coords[0..mid], grid_size);
+        let path_len = find_path(&grid, grid_size);
+
+        if mid == start || mid == end {
+            return Some(coords[mid]);
+        }
+
+        if path_len.is_none() {
+            end = mid;
+        } else {
+            start = mid;
+        }
+    }
+
+    None
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+
+    type Input = Vec<Coords>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(coords: &Self::Input) -> Result<usize> {
+        let grid = corrupt_grid(&coords[0..1024], GRID_SIZE);
+        Ok(find_path(&grid, GRID_SIZE).unwrap())
+    }
+
+    fn part2(coords: &Self::Input) -> Result<String> {
+        let (x, y) = find_blocking_coord(coords, GRID_SIZE).unwrap();
+        Ok(format!("{},{}", x, y))
+    }
+
+    fn animate(coords: &Self::Input) -> Option<Animation> {
+        let coords = coords.clone();
+        let mut grid = corrupt_grid(&[], GRID_SIZE);
+        let mut path = shortest_path(&grid, GRID_SIZE).unwrap_or_default();
+
+        let mut blocked = false;
+
+        // a byte at a time, finding a new path whenever one falls on the old one, up to the byte
+        // that blocks the way out
+        let frames = std::iter::once(draw_map(&grid, &path)).chain(coords.into_iter().map_while(
+            move |pos| {
+                if blocked {
+                    return None;
+                }
+
+                grid[pos] = true;
+                if path.contains(&pos) {
+                    match shortest_path(&grid, GRID_SIZE) {
+                        Some(new_path) => path = new_path,
+                        None => {
+                            blocked = true;
+                            path.clear();
+                        }
+                    }
+                }
+
+                Some(draw_map(&grid, &path))
+            },
+        ));
+
+        Some(
+            Animation::new(frames).fps(30.0).palette(
+                Palette::new()
+                    .with("#", Color::Red)
+                    .with("O", Color::BrightGreen),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE_POSITIONS: &str = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0";
+    static EXAMPLE_GRID_SIZE: usize = 6;
+
+    #[test]
+    fn test_find_path() {
+        let coords = parse_input(EXAMPLE_POSITIONS);
+        let grid = corrupt_grid(&coords[0..12], EXAMPLE_GRID_SIZE);
+        assert_eq!(find_path(&grid, EXAMPLE_GRID_SIZE), Some(22));
+    }
+
+    #[test]
+    fn test_find_blocking_coord() {
+        let coords = parse_input(EXAMPLE_POSITIONS);
+        assert_eq!(
+            find_blocking_coord(&coords, EXAMPLE_GRID_SIZE),
+            Some((6, 1))
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use aocutils::{Day, Stats, Timings};
+use serde::{Deserialize, Serialize};
+
+/// Machine readable results of a benchmark run, so later runs can be compared against it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub runs: usize,
+    pub days: Vec<DayReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub parse: PhaseReport,
+    pub part1: Option<PhaseReport>,
+    pub part2: Option<PhaseReport>,
+}
+
+/// Timings of a single phase, in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PhaseReport {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl From<Stats> for PhaseReport {
+    fn from(stats: Stats) -> Self {
+        let ns = |d: Duration| d.as_nanos().try_into().unwrap_or(u64::MAX);
+
+        PhaseReport {
+            min_ns: ns(stats.min),
+            median_ns: ns(stats.median),
+            p95_ns: ns(stats.p95),
+        }
+    }
+}
+
+impl DayReport {
+    pub fn new(day: &Day, timings: Timings) -> Self {
+        DayReport {
+            year: day.year,
+            day: day.day,
+            parse: timings.parse.into(),
+            part1: timings.part1.map(|s| s.into()),
+            part2: timings.part2.map(|s| s.into()),
+        }
+    }
+
+    fn phases(&self) -> [(&'static str, Option<PhaseReport>); 3] {
+        [
+            ("parse", Some(self.parse)),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+    }
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(ns))
+}
+
+/// Render a report as a table with one row per phase of each day
+pub fn table(report: &Report) -> String {
+    let mut table = format!(
+        "{:<10} {:<8} {:>12} {:>12} {:>12}\n",
+        "day", "phase", "min", "median", "p95"
+    );
+
+    for day in &report.days {
+        for (phase, timings) in day.phases() {
+            let Some(timings) = timings else {
+                continue;
+            };
+
+            table += &format!(
+                "{:<10} {:<8} {:>12} {:>12} {:>12}\n",
+                format!("{}/{:02}", day.year, day.day),
+                phase,
+                format_ns(timings.min_ns),
+                format_ns(timings.median_ns),
+                format_ns(timings.p95_ns)
+            );
+        }
+    }
+
+    table
+}
+
+/// A phase whose median time got worse than the allowed threshold
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub year: u16,
+    pub day: u8,
+    pub phase: &'static str,
+    pub before_ns: u64,
+    pub after_ns: u64,
+    pub change_pct: f64,
+}
+
+impl std::fmt::Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{:02} {}: {} -> {} (+{:.1}%)",
+            self.year,
+            self.day,
+            self.phase,
+            format_ns(self.before_ns),
+            format_ns(self.after_ns),
+            self.change_pct
+        )
+    }
+}
+
+/// Compare median timings against a baseline, returning every phase that is more than `threshold_pct` slower.
+/// Days or phases missing from either report are skipped.
+pub fn regressions(baseline: &Report, current: &Report, threshold_pct: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+
+    for day in &current.days {
+        let Some(before) = baseline
+            .days
+            .iter()
+            .find(|d| d.year == day.year && d.day == day.day)
+        else {
+            continue;
+        };
+
+        for ((phase, after), (_, before)) in day.phases().into_iter().zip(before.phases()) {
+            let (Some(after), Some(before)) = (after, before) else {
+                continue;
+            };
+            if before.median_ns == 0 {
+                continue;
+            }
+
+            let change_pct = (after.median_ns as f64 - before.median_ns as f64)
+                / before.median_ns as f64
+                * 100.0;
+            if change_pct > threshold_pct {
+                regressions.push(Regression {
+                    year: day.year,
+                    day: day.day,
+                    phase,
+                    before_ns: before.median_ns,
+                    after_ns: after.median_ns,
+                    change_pct,
+                });
+            }
+        }
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase(median_ns: u64) -> PhaseReport {
+        PhaseReport {
+            min_ns: median_ns / 2,
+            median_ns,
+            p95_ns: median_ns * 2,
+        }
+    }
+
+    fn report(parse: u64, part1: Option<u64>) -> Report {
+        Report {
+            runs: 10,
+            days: vec![DayReport {
+                year: 2024,
+                day: 7,
+                parse: phase(parse),
+                part1: part1.map(phase),
+                part2: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = report(1000, Some(2000));
+
+        assert_eq!(
+            regressions(&baseline, &report(1050, Some(2100)), 10.0),
+            vec![]
+        );
+        assert_eq!(
+            regressions(&baseline, &report(1050, Some(3000)), 10.0),
+            vec![Regression {
+                year: 2024,
+                day: 7,
+                phase: "part 1",
+                before_ns: 2000,
+                after_ns: 3000,
+                change_pct: 50.0,
+            }]
+        );
+        // phases that weren't in the baseline can't regress
+        assert_eq!(
+            regressions(&report(1000, None), &report(1000, Some(3000)), 10.0),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let report = report(1000, Some(2000));
+        let json = serde_json::to_string(&report).unwrap();
+
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+    }
+
+    #[test]
+    fn test_table() {
+        let table = table(&report(1500, None));
+
+        assert_eq!(table.lines().count(), 2);
+        assert!(table
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("2024/07    parse"));
+    }
+}
@@ -0,0 +1,58 @@
+use aocutils::Registry;
+
+/// Every solved day, across all years
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry
+        .register::<aoc2022_day01::Day01>()
+        .register::<aoc2022_day02::Day02>()
+        .register::<aoc2022_day03::Day03>()
+        .register::<aoc2022_day04::Day04>()
+        .register::<aoc2022_day05::Day05>()
+        .register::<aoc2022_day06::Day06>()
+        .register::<aoc2022_day07::Day07>()
+        .register::<aoc2022_day08::Day08>()
+        .register::<aoc2022_day09::Day09>()
+        .register::<aoc2022_day12::Day12>()
+        .register::<aoc2022_day13::Day13>()
+        .register::<aoc2022_day14::Day14>()
+        .register::<aoc2022_day15::Day15>()
+        .register::<aoc2022_day16::Day16>()
+        .register::<aoc2022_day17::Day17>()
+        .register::<aoc2022_day18::Day18>()
+        .register::<aoc2022_day20::Day20>()
+        .register::<aoc2022_day21::Day21>()
+        .register::<aoc2023_day01::Day01>()
+        .register::<aoc2023_day02::Day02>()
+        .register::<aoc2023_day03::Day03>()
+        .register::<aoc2023_day04::Day04>()
+        .register::<aoc2023_day05::Day05>()
+        .register::<aoc2023_day06::Day06>()
+        .register::<aoc2023_day07::Day07>()
+        .register::<aoc2023_day08::Day08>()
+        .register::<aoc2023_day11::Day11>()
+        .register::<aoc2023_day12::Day12>()
+        .register::<aoc2023_day13::Day13>()
+        .register::<aoc2023_day14::Day14>()
+        .register::<aoc2024_day01::Day01>()
+        .register::<aoc2024_day02::Day02>()
+        .register::<aoc2024_day03::Day03>()
+        .register::<aoc2024_day04::Day04>()
+        .register::<aoc2024_day05::Day05>()
+        .register::<aoc2024_day06::Day06>()
+        .register::<aoc2024_day07::Day07>()
+        .register::<aoc2024_day08::Day08>()
+        .register::<aoc2024_day09::Day09>()
+        .register::<aoc2024_day10::Day10>()
+        .register::<aoc2024_day11::Day11>()
+        .register::<aoc2024_day12::Day12>()
+        .register::<aoc2024_day13::Day13>()
+        .register::<aoc2024_day14::Day14>()
+        .register::<aoc2024_day15::Day15>()
+        .register::<aoc2024_day16::Day16>()
+        .register::<aoc2024_day17::Day17>()
+        .register::<aoc2024_day18::Day18>();
+
+    registry
+}
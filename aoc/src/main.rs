@@ -0,0 +1,526 @@
+use std::{
+    error::Error,
+    fs,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aocutils::{
+    answers_path, find_input, read_answers,
+    viz::{Animation, Palette},
+    write_answers, Answers, Day, Registry,
+};
+use clap::{Parser, Subcommand};
+
+mod bench;
+mod days;
+mod scaffold;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Run Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, a whole year, or everything
+    Run {
+        #[arg(long, required_unless_present = "all")]
+        year: Option<u16>,
+        #[arg(long, requires = "year")]
+        day: Option<u8>,
+        /// Only print the answer for this part
+        #[arg(long, requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file instead, or from stdin if it is `-`
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        #[arg(long, conflicts_with = "year")]
+        all: bool,
+    },
+    /// Time parsing and each part separately, over every day or the ones selected
+    Bench {
+        #[arg(long)]
+        year: Option<u16>,
+        #[arg(long, requires = "year")]
+        day: Option<u8>,
+        /// How many times to run each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        /// Write a JSON report of the timings to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Compare against a JSON report from an earlier run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// How many percent slower a median time can get before it counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check answers against the ones recorded in each day's `inputs/answers.toml`
+    Verify {
+        #[arg(long)]
+        year: Option<u16>,
+        #[arg(long, requires = "year")]
+        day: Option<u8>,
+        /// Record the current answers for any parts that don't have one yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Play a day's animation in the terminal, for days that have one
+    Animate {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Read the input from this file instead, or from stdin if it is `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Frames per second to start at, instead of the day's own speed
+        #[arg(long, value_parser = parse_fps)]
+        fps: Option<f64>,
+        /// Don't colour the frames
+        #[arg(long)]
+        no_colour: bool,
+    },
+    /// Print what a day worked out on the way to its answers, for days that can explain them
+    Explain {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Read the input from this file instead, or from stdin if it is `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Save a day's animation as a GIF, or one frame of it as a PNG or PPM image
+    Export {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Read the input from this file instead, or from stdin if it is `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Where to save it, as a .gif, .png or .ppm file
+        #[arg(long)]
+        out: PathBuf,
+        /// How many pixels wide each character of a frame is drawn
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..=64))]
+        scale: u64,
+        /// The first frame of a GIF, or the one frame saved as an image
+        #[arg(long, default_value_t = 0)]
+        from: usize,
+        /// Stop a GIF before this frame, instead of at the end of the animation
+        #[arg(long)]
+        to: Option<usize>,
+        /// Frames per second of a GIF, instead of the day's own speed
+        #[arg(long, value_parser = parse_fps)]
+        fps: Option<f64>,
+    },
+    /// Generate a crate for a new day and register it with the runner
+    New {
+        #[arg(long)]
+        year: u16,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// List every registered day
+    List,
+}
+
+fn parse_fps(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(fps) if fps.is_finite() && fps > 0.0 => Ok(fps),
+        Ok(_) => Err("must be a positive number".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner should be inside the workspace")
+}
+
+fn crate_dir(day: &Day) -> PathBuf {
+    // day crates live under their year directory at the workspace root
+    workspace_dir()
+        .join(day.year.to_string())
+        .join(format!("day{:02}", day.day))
+}
+
+fn read_input(day: &Day, input: Option<&Path>) -> aocutils::Result<String> {
+    match input {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => aocutils::Error::MissingInput {
+                name: path.display().to_string(),
+                tried: vec![path.to_path_buf()],
+            },
+            _ => e.into(),
+        }),
+        None => Ok(fs::read_to_string(find_input(&crate_dir(day), "input")?)?),
+    }
+}
+
+/// Format an error, showing where in the input a parse error happened
+fn describe(e: aocutils::Error, input: &str) -> String {
+    match e.snippet(input) {
+        Some(snippet) => format!("{}\n{}", e, snippet),
+        None => e.to_string(),
+    }
+}
+
+fn run_day(day: &Day, part: Option<u8>, input: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let input = read_input(day, input)?;
+    let input = input.trim_end();
+
+    println!("{} day {:02}", day.year, day.day);
+    let answers = day.run(input).map_err(|e| describe(e, input))?;
+
+    for (n, answer) in [(1, answers.part1), (2, answers.part2)] {
+        if part.is_some_and(|p| p != n) {
+            continue;
+        }
+
+        println!(
+            "  part {}: {}",
+            n,
+            answer.unwrap_or_else(|| "unsolved".to_string())
+        );
+    }
+
+    Ok(())
+}
+
+fn load_animation(day: &Day, input: Option<&Path>) -> Result<Animation, Box<dyn Error>> {
+    let input = read_input(day, input)?;
+    let input = input.trim_end();
+
+    let animation = day
+        .animate(input)
+        .map_err(|e| describe(e, input))?
+        .ok_or(format!(
+            "{} day {} doesn't have an animation",
+            day.year, day.day
+        ))?;
+
+    Ok(animation)
+}
+
+fn explain_day(day: &Day, input: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let input = read_input(day, input)?;
+    let input = input.trim_end();
+
+    let explanation = day
+        .explain(input)
+        .map_err(|e| describe(e, input))?
+        .ok_or(format!(
+            "{} day {} doesn't have an explanation",
+            day.year, day.day
+        ))?;
+
+    println!("{}", explanation.trim_end());
+    Ok(())
+}
+
+fn animate_day(
+    day: &Day,
+    input: Option<&Path>,
+    fps: Option<f64>,
+    no_colour: bool,
+) -> Result<(), Box<dyn Error>> {
+    if !io::stdout().is_terminal() {
+        return Err("animations can only be played in a terminal".into());
+    }
+
+    let mut animation = load_animation(day, input)?;
+    if let Some(fps) = fps {
+        animation = animation.fps(fps);
+    }
+    if no_colour {
+        animation = animation.palette(Palette::new());
+    }
+
+    Ok(animation.play()?)
+}
+
+fn export_day(
+    day: &Day,
+    input: Option<&Path>,
+    out: &Path,
+    scale: usize,
+    (from, to): (usize, Option<usize>),
+    fps: Option<f64>,
+) -> Result<(), Box<dyn Error>> {
+    let mut animation = load_animation(day, input)?.slice(from, to);
+    if let Some(fps) = fps {
+        animation = animation.fps(fps);
+    }
+
+    if out
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("gif"))
+    {
+        animation.save_gif(out, scale)?;
+    } else {
+        let image = animation
+            .images(scale)
+            .next()
+            .ok_or(format!("the animation has no frame {}", from))?;
+        image.save(out)?;
+    }
+
+    println!("saved {}", out.display());
+    Ok(())
+}
+
+fn select_days(
+    registry: &Registry,
+    year: Option<u16>,
+    day: Option<u8>,
+) -> Result<Vec<&Day>, Box<dyn Error>> {
+    match (year, day) {
+        (Some(year), Some(day)) => Ok(vec![registry
+            .get(year, day)
+            .ok_or(format!("{} day {} has not been solved", year, day))?]),
+        (Some(year), None) => Ok(registry.year(year).collect()),
+        (None, _) => Ok(registry.iter().collect()),
+    }
+}
+
+fn bench_days(
+    days: &[&Day],
+    runs: usize,
+    json: Option<&Path>,
+    baseline: Option<&Path>,
+    threshold: f64,
+) -> Result<(), Box<dyn Error>> {
+    let mut report = bench::Report {
+        runs,
+        days: Vec::new(),
+    };
+
+    for day in days {
+        eprintln!("benchmarking {} day {:02}...", day.year, day.day);
+
+        let input = read_input(day, None)?;
+        let input = input.trim_end();
+        let timings = day.bench(input, runs).map_err(|e| describe(e, input))?;
+        report.days.push(bench::DayReport::new(day, timings));
+    }
+
+    print!("{}", bench::table(&report));
+
+    if let Some(path) = json {
+        fs::write(path, serde_json::to_string_pretty(&report)?)?;
+    }
+
+    if let Some(path) = baseline {
+        let baseline: bench::Report = serde_json::from_str(&fs::read_to_string(path)?)?;
+        let regressions = bench::regressions(&baseline, &report, threshold);
+
+        if !regressions.is_empty() {
+            println!("\nregressions:");
+            for regression in &regressions {
+                println!("  {}", regression);
+            }
+
+            return Err(format!(
+                "{} phase(s) got more than {}% slower than {}",
+                regressions.len(),
+                threshold,
+                path.display()
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Run a day on its usual input, returning where the input was found so the answers next to it can be read
+fn run_for_verify(day: &Day) -> aocutils::Result<(PathBuf, Answers)> {
+    let path = find_input(&crate_dir(day), "input")?;
+    let input = fs::read_to_string(&path)?;
+    let answers = day.run(input.trim_end())?;
+
+    Ok((path, answers))
+}
+
+fn verify_day(
+    day: &Day,
+    record: bool,
+    problems: &mut Vec<String>,
+) -> aocutils::Result<[verify::Status; 2]> {
+    let (input_path, actual) = run_for_verify(day)?;
+    let path = answers_path(&input_path);
+    let expected = read_answers(&path)?;
+    let parts = verify::check(expected.as_ref(), &actual);
+
+    let got = [&actual.part1, &actual.part2];
+    let wanted = [
+        expected.as_ref().and_then(|e| e.part1.as_ref()),
+        expected.as_ref().and_then(|e| e.part2.as_ref()),
+    ];
+    for (n, status) in parts.iter().enumerate() {
+        if *status == verify::Status::Fail {
+            problems.push(format!(
+                "{}/{:02} part {}: expected {}, got {}",
+                day.year,
+                day.day,
+                n + 1,
+                wanted[n].map_or("nothing", |s| s.as_str()),
+                got[n].as_deref().unwrap_or("nothing")
+            ));
+        }
+    }
+
+    if record && parts.contains(&verify::Status::Missing) {
+        // only fill in the gaps, a failing answer needs looking at rather than overwriting
+        let expected = expected.unwrap_or_default();
+        write_answers(
+            &path,
+            &Answers {
+                part1: expected.part1.or(actual.part1),
+                part2: expected.part2.or(actual.part2),
+            },
+        )?;
+        println!("recorded answers to {}", path.display());
+    }
+
+    Ok(parts)
+}
+
+fn verify_days(days: &[&Day], record: bool) -> Result<(), Box<dyn Error>> {
+    let mut results = Vec::new();
+    let mut problems = Vec::new();
+
+    for day in days {
+        let parts = verify_day(day, record, &mut problems).unwrap_or_else(|e| {
+            problems.push(format!("{}/{:02}: {}", day.year, day.day, e));
+            [verify::Status::Error; 2]
+        });
+
+        results.push(verify::DayResult {
+            year: day.year,
+            day: day.day,
+            parts,
+        });
+    }
+
+    print!("{}", verify::matrix(&results));
+
+    if !problems.is_empty() {
+        println!();
+        for problem in &problems {
+            println!("{}", problem);
+        }
+
+        return Err(format!("{} problem(s) found while verifying", problems.len()).into());
+    }
+
+    Ok(())
+}
+
+fn run(command: Command) -> Result<(), Box<dyn Error>> {
+    let registry = days::registry();
+
+    match command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            ..
+        } => {
+            for day in select_days(&registry, year, day)? {
+                run_day(day, part, input.as_deref())?;
+            }
+        }
+        Command::Bench {
+            year,
+            day,
+            runs,
+            json,
+            baseline,
+            threshold,
+        } => {
+            let days = select_days(&registry, year, day)?;
+            bench_days(
+                &days,
+                runs as usize,
+                json.as_deref(),
+                baseline.as_deref(),
+                threshold,
+            )?;
+        }
+        Command::Verify { year, day, record } => {
+            verify_days(&select_days(&registry, year, day)?, record)?;
+        }
+        Command::Animate {
+            year,
+            day,
+            input,
+            fps,
+            no_colour,
+        } => {
+            let day = select_days(&registry, Some(year), Some(day))?[0];
+            animate_day(day, input.as_deref(), fps, no_colour)?;
+        }
+        Command::Explain { year, day, input } => {
+            let day = select_days(&registry, Some(year), Some(day))?[0];
+            explain_day(day, input.as_deref())?;
+        }
+        Command::Export {
+            year,
+            day,
+            input,
+            out,
+            scale,
+            from,
+            to,
+            fps,
+        } => {
+            let day = select_days(&registry, Some(year), Some(day))?[0];
+            export_day(day, input.as_deref(), &out, scale as usize, (from, to), fps)?;
+        }
+        Command::New { year, day } => {
+            let crate_dir = scaffold::new_day(workspace_dir(), year, day)?;
+            println!("created {}", crate_dir.display());
+            println!(
+                "put the puzzle input in {}",
+                crate_dir.join("inputs").join("input.txt").display()
+            );
+        }
+        Command::List => {
+            for day in registry.iter() {
+                println!("{} day {:02}", day.year, day.day);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    // print errors with `Display` rather than the `Debug` output returning them from main would give
+    match run(cli.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
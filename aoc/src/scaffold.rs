@@ -0,0 +1,258 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &format!("{:02}", day))
+        .replace("{day_number}", &day.to_string())
+}
+
+/// Key of a line like `aoc2024-day07 = { path = "../2024/day07" }` in the runner's manifest
+fn dependency_key(line: &str) -> Option<(u16, u8)> {
+    let (name, _) = line.split_once(" = ")?;
+    let (year, day) = name.strip_prefix("aoc")?.split_once("-day")?;
+
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Key of a line like `.register::<aoc2024_day07::Day07>()` in the runner's registry
+fn registration_key(line: &str) -> Option<(u16, u8)> {
+    let name = line.trim().strip_prefix(".register::<aoc")?;
+    let (year, rest) = name.split_once("_day")?;
+
+    Some((year.parse().ok()?, rest.get(..2)?.parse().ok()?))
+}
+
+/// Insert `new_line` among the lines that `key` recognises, keeping them sorted. Returns `None` if the key is
+/// already there or there aren't any lines to insert it among.
+fn insert_sorted(
+    contents: &str,
+    key: fn(&str) -> Option<(u16, u8)>,
+    new_key: (u16, u8),
+    new_line: &str,
+) -> Option<Vec<String>> {
+    let mut lines = contents.lines().map(|l| l.to_string()).collect::<Vec<_>>();
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, key(l)?)))
+        .collect::<Vec<_>>();
+
+    if keyed.iter().any(|(_, k)| *k == new_key) {
+        return None;
+    }
+
+    let idx = match keyed.iter().find(|(_, k)| *k > new_key) {
+        Some((i, _)) => *i,
+        None => keyed.last()?.0 + 1,
+    };
+    lines.insert(idx, new_line.to_string());
+
+    Some(lines)
+}
+
+fn join_lines(lines: Vec<String>) -> String {
+    lines.join("\n") + "\n"
+}
+
+/// Add a day crate to the runner's dependencies
+pub fn insert_dependency(manifest: &str, year: u16, day: u8) -> Option<String> {
+    let line = format!(
+        "aoc{}-day{:02} = {{ path = \"../{}/day{:02}\" }}",
+        year, day, year, day
+    );
+
+    insert_sorted(manifest, dependency_key, (year, day), &line).map(join_lines)
+}
+
+/// Add a day to the runner's registry, which is one chain of `register` calls ending in a `;`
+pub fn insert_registration(days: &str, year: u16, day: u8) -> Option<String> {
+    let line = format!(
+        "        .register::<aoc{}_day{:02}::Day{:02}>()",
+        year, day, day
+    );
+    let mut lines = insert_sorted(days, registration_key, (year, day), &line)?;
+
+    // keep the semicolon on whichever line now ends the chain
+    let last = lines.iter().rposition(|l| registration_key(l).is_some())?;
+    for l in lines.iter_mut() {
+        if registration_key(l).is_some() {
+            *l = l.trim_end_matches(';').to_string();
+        }
+    }
+    lines[last].push(';');
+
+    Some(join_lines(lines))
+}
+
+/// Add a year's days to the workspace members if they aren't already, e.g. when starting a new year
+pub fn insert_workspace_member(manifest: &str, year: u16) -> String {
+    let member = format!("\"{}/day*\"", year);
+
+    manifest
+        .lines()
+        .map(|l| match l.strip_prefix("members = [") {
+            Some(members) if !members.contains(&member) => {
+                format!("members = [{}, {}]", members.trim_end_matches(']'), member)
+            }
+            _ => l.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+/// Generate a new day crate under `workspace` and register it with the runner, returning the crate's directory
+pub fn new_day(workspace: &Path, year: u16, day: u8) -> Result<PathBuf, Box<dyn Error>> {
+    let crate_dir = workspace
+        .join(year.to_string())
+        .join(format!("day{:02}", day));
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()).into());
+    }
+
+    let workspace_path = workspace.join("Cargo.toml");
+    let manifest_path = workspace.join("aoc").join("Cargo.toml");
+    let days_path = workspace.join("aoc").join("src").join("days.rs");
+    let manifest = insert_dependency(&fs::read_to_string(&manifest_path)?, year, day).ok_or(
+        format!("{} day {} is already a dependency of the runner", year, day),
+    )?;
+    let days = insert_registration(&fs::read_to_string(&days_path)?, year, day)
+        .ok_or(format!("{} day {} is already registered", year, day))?;
+    let workspace_manifest = insert_workspace_member(&fs::read_to_string(&workspace_path)?, year);
+
+    fs::create_dir_all(crate_dir.join("src"))?;
+    fs::create_dir_all(crate_dir.join("inputs"))?;
+    fs::write(
+        crate_dir.join("Cargo.toml"),
+        render(CARGO_TEMPLATE, year, day),
+    )?;
+    fs::write(
+        crate_dir.join("src").join("lib.rs"),
+        render(LIB_TEMPLATE, year, day),
+    )?;
+    fs::write(workspace_path, workspace_manifest)?;
+    fs::write(manifest_path, manifest)?;
+    fs::write(days_path, days)?;
+
+    Ok(crate_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[dependencies]
+aocutils = { version = \"0.1.0\", path = \"../aocutils\" }
+
+aoc2023-day05 = { path = \"../2023/day05\" }
+aoc2024-day07 = { path = \"../2024/day07\" }
+";
+
+    const DAYS: &str = "pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry
+        .register::<aoc2023_day05::Day05>()
+        .register::<aoc2024_day07::Day07>();
+
+    registry
+}
+";
+
+    #[test]
+    fn test_render() {
+        let lib = render(LIB_TEMPLATE, 2024, 9);
+
+        assert!(lib.contains("pub struct Day09;"));
+        assert!(lib.contains("const YEAR: u16 = 2024;"));
+        assert!(lib.contains("const DAY: u8 = 9;"));
+        assert!(render(CARGO_TEMPLATE, 2024, 9).contains("name = \"aoc2024-day09\""));
+    }
+
+    #[test]
+    fn test_insert_dependency() {
+        assert_eq!(
+            insert_dependency(MANIFEST, 2024, 1).unwrap(),
+            "[dependencies]
+aocutils = { version = \"0.1.0\", path = \"../aocutils\" }
+
+aoc2023-day05 = { path = \"../2023/day05\" }
+aoc2024-day01 = { path = \"../2024/day01\" }
+aoc2024-day07 = { path = \"../2024/day07\" }
+"
+        );
+        assert!(insert_dependency(MANIFEST, 2025, 1)
+            .unwrap()
+            .ends_with("aoc2025-day01 = { path = \"../2025/day01\" }\n"));
+        assert_eq!(insert_dependency(MANIFEST, 2024, 7), None);
+    }
+
+    #[test]
+    fn test_insert_registration() {
+        assert_eq!(
+            insert_registration(DAYS, 2024, 8).unwrap(),
+            "pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry
+        .register::<aoc2023_day05::Day05>()
+        .register::<aoc2024_day07::Day07>()
+        .register::<aoc2024_day08::Day08>();
+
+    registry
+}
+"
+        );
+        assert!(insert_registration(DAYS, 2022, 1).unwrap().contains(
+            "        .register::<aoc2022_day01::Day01>()\n        .register::<aoc2023_day05"
+        ));
+        assert_eq!(insert_registration(DAYS, 2023, 5), None);
+    }
+
+    #[test]
+    fn test_insert_workspace_member() {
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"2024/day*\"]\n";
+
+        assert_eq!(insert_workspace_member(manifest, 2024), manifest);
+        assert_eq!(
+            insert_workspace_member(manifest, 2025),
+            "[workspace]\nmembers = [\"aoc\", \"2024/day*\", \"2025/day*\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_new_day_refuses_to_overwrite() {
+        let workspace = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(workspace.join("aoc").join("src")).unwrap();
+        fs::write(
+            workspace.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\"]\n",
+        )
+        .unwrap();
+        fs::write(workspace.join("aoc").join("Cargo.toml"), MANIFEST).unwrap();
+        fs::write(workspace.join("aoc").join("src").join("days.rs"), DAYS).unwrap();
+
+        let crate_dir = new_day(&workspace, 2024, 8).unwrap();
+        let created = (
+            crate_dir.join("Cargo.toml").is_file(),
+            crate_dir.join("src").join("lib.rs").is_file(),
+            crate_dir.join("inputs").is_dir(),
+        );
+        let again = new_day(&workspace, 2024, 8);
+        // already registered even though the crate itself is missing
+        let registered = new_day(&workspace, 2024, 7);
+        fs::remove_dir_all(&workspace).unwrap();
+
+        assert_eq!(created, (true, true, true));
+        assert!(again.is_err());
+        assert!(registered.is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::{self, Display};
+
+use aocutils::Answers;
+
+/// Outcome of checking one part against its recorded answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// Nothing has been recorded for this part yet
+    Missing,
+    /// The part hasn't been solved and there is nothing recorded for it
+    Unsolved,
+    /// The day couldn't be run, e.g. because its input is missing
+    Error,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // pad here so that the matrix columns line up
+        let s = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Unsolved => "unsolved",
+            Status::Error => "ERROR",
+        };
+        f.pad(s)
+    }
+}
+
+pub fn check_part(expected: Option<&str>, actual: Option<&str>) -> Status {
+    match (expected, actual) {
+        (Some(expected), Some(actual)) if expected == actual => Status::Pass,
+        (Some(_), _) => Status::Fail,
+        (None, Some(_)) => Status::Missing,
+        (None, None) => Status::Unsolved,
+    }
+}
+
+pub fn check(expected: Option<&Answers>, actual: &Answers) -> [Status; 2] {
+    [
+        check_part(
+            expected.and_then(|e| e.part1.as_deref()),
+            actual.part1.as_deref(),
+        ),
+        check_part(
+            expected.and_then(|e| e.part2.as_deref()),
+            actual.part2.as_deref(),
+        ),
+    ]
+}
+
+/// Verification results of a single day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub parts: [Status; 2],
+}
+
+/// Render results as a matrix with a row per day and a column per part, followed by totals
+pub fn matrix(results: &[DayResult]) -> String {
+    let mut matrix = format!("{:<10} {:<10} {}\n", "day", "part 1", "part 2");
+
+    for result in results {
+        matrix += &format!(
+            "{:<10} {:<10} {}\n",
+            format!("{}/{:02}", result.year, result.day),
+            result.parts[0],
+            result.parts[1]
+        );
+    }
+
+    let count = |status: Status| {
+        results
+            .iter()
+            .flat_map(|r| r.parts)
+            .filter(|s| *s == status)
+            .count()
+    };
+    matrix += &format!(
+        "\n{} passed, {} failed, {} missing, {} errors\n",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing),
+        count(Status::Error)
+    );
+
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(part1: Option<&str>, part2: Option<&str>) -> Answers {
+        Answers {
+            part1: part1.map(|s| s.to_string()),
+            part2: part2.map(|s| s.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let actual = answers(Some("143"), Some("123"));
+
+        assert_eq!(check(None, &actual), [Status::Missing, Status::Missing]);
+        assert_eq!(
+            check(Some(&answers(Some("143"), Some("124"))), &actual),
+            [Status::Pass, Status::Fail]
+        );
+        assert_eq!(
+            check(Some(&answers(Some("143"), None)), &actual),
+            [Status::Pass, Status::Missing]
+        );
+        // a recorded answer for a part that now returns nothing is a regression
+        assert_eq!(
+            check(
+                Some(&answers(Some("143"), Some("123"))),
+                &answers(Some("143"), None)
+            ),
+            [Status::Pass, Status::Fail]
+        );
+        assert_eq!(
+            check(None, &answers(Some("143"), None)),
+            [Status::Missing, Status::Unsolved]
+        );
+    }
+
+    #[test]
+    fn test_matrix() {
+        let matrix = matrix(&[
+            DayResult {
+                year: 2022,
+                day: 1,
+                parts: [Status::Pass, Status::Pass],
+            },
+            DayResult {
+                year: 2024,
+                day: 13,
+                parts: [Status::Fail, Status::Unsolved],
+            },
+        ]);
+
+        assert_eq!(
+            matrix,
+            "day        part 1     part 2\n\
+             2022/01    pass       pass\n\
+             2024/13    FAIL       unsolved\n\
+             \n\
+             2 passed, 1 failed, 0 missing, 0 errors\n"
+        );
+    }
+}
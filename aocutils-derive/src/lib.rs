@@ -0,0 +1,297 @@
+//! Derive macros for `aocutils`. Use them through the re-exports in `aocutils`, as the generated code
+//! refers to it by name.
+
+use std::collections::BTreeMap;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitStr};
+
+/// A placeholder in a pattern, either `{field}` or `{field.0}` for one element of a tuple field
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Placeholder {
+    field: String,
+    index: Option<usize>,
+}
+
+/// A pattern split into the literal text around each placeholder, so there is always one more
+/// literal than there are placeholders
+#[derive(Debug, PartialEq, Eq)]
+struct Pattern {
+    literals: Vec<String>,
+    placeholders: Vec<Placeholder>,
+}
+
+fn parse_pattern(pattern: &str) -> Result<Pattern, String> {
+    let mut literals = vec![String::new()];
+    let mut placeholders = Vec::new();
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err("unmatched '{'".to_string()),
+                    }
+                }
+
+                let placeholder = match name.split_once('.') {
+                    Some((field, index)) => Placeholder {
+                        field: field.to_string(),
+                        index: Some(
+                            index
+                                .parse()
+                                .map_err(|_| format!("'{}' is not a tuple index", index))?,
+                        ),
+                    },
+                    None => Placeholder {
+                        field: name,
+                        index: None,
+                    },
+                };
+
+                if placeholder.field.is_empty() {
+                    return Err("placeholders need a field name".to_string());
+                }
+                if !placeholders.is_empty() && literals[literals.len() - 1].is_empty() {
+                    return Err(format!(
+                        "there must be some text between placeholders before {{{}}}",
+                        placeholder.field
+                    ));
+                }
+                if placeholders.contains(&placeholder) {
+                    return Err(format!("{{{}}} is used more than once", placeholder.field));
+                }
+
+                placeholders.push(placeholder);
+                literals.push(String::new());
+            }
+            '}' => return Err("unmatched '}'".to_string()),
+            _ => literals.last_mut().unwrap().push(c),
+        }
+    }
+
+    Ok(Pattern {
+        literals,
+        placeholders,
+    })
+}
+
+/// Find the `pattern = "..."` in the `#[aoc(...)]` attribute
+fn find_pattern(input: &DeriveInput) -> syn::Result<LitStr> {
+    let mut pattern = None;
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("aoc")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("pattern") {
+                pattern = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `pattern = \"...\"`"))
+            }
+        })?;
+    }
+
+    pattern.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "deriving FromInput needs an #[aoc(pattern = \"...\")] attribute",
+        )
+    })
+}
+
+fn local_name(placeholder: &Placeholder) -> Ident {
+    match placeholder.index {
+        Some(i) => format_ident!("__{}_{}", placeholder.field, i),
+        None => format_ident!("__{}", placeholder.field),
+    }
+}
+
+fn derive_from_input(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let pattern_lit = find_pattern(&input)?;
+    let pattern = parse_pattern(&pattern_lit.value())
+        .map_err(|e| syn::Error::new_spanned(&pattern_lit, e))?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .map(|f| f.ident.clone().unwrap())
+                .collect::<Vec<_>>(),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "FromInput can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "FromInput can only be derived for structs",
+            ))
+        }
+    };
+
+    // which placeholders fill each field, by tuple index
+    let mut filled: BTreeMap<String, Vec<Option<usize>>> = BTreeMap::new();
+    for placeholder in &pattern.placeholders {
+        if !fields.iter().any(|f| *f == placeholder.field) {
+            return Err(syn::Error::new_spanned(
+                &pattern_lit,
+                format!("{} has no field called '{}'", name, placeholder.field),
+            ));
+        }
+        filled
+            .entry(placeholder.field.clone())
+            .or_default()
+            .push(placeholder.index);
+    }
+
+    let mut constructors = Vec::new();
+    for field in &fields {
+        let Some(indices) = filled.get_mut(&field.to_string()) else {
+            return Err(syn::Error::new_spanned(
+                &pattern_lit,
+                format!("the pattern doesn't fill in '{}'", field),
+            ));
+        };
+        indices.sort();
+
+        let value = if *indices == [None] {
+            let local = format_ident!("__{}", field);
+            quote!(#local)
+        } else if indices
+            .iter()
+            .enumerate()
+            .all(|(i, &index)| index == Some(i))
+        {
+            let locals = (0..indices.len()).map(|i| format_ident!("__{}_{}", field, i));
+            quote!((#(#locals,)*))
+        } else {
+            return Err(syn::Error::new_spanned(
+                &pattern_lit,
+                format!(
+                    "'{}' must either be filled in whole, or by every index from {{{}.0}} up",
+                    field, field
+                ),
+            ));
+        };
+
+        constructors.push(quote!(#field: #value));
+    }
+
+    let first = &pattern.literals[0];
+    let mut steps = vec![quote! {
+        let rest = span.tag(#first)?;
+    }];
+
+    for (placeholder, literal) in pattern.placeholders.iter().zip(&pattern.literals[1..]) {
+        let local = local_name(placeholder);
+
+        steps.push(if literal.is_empty() {
+            // only the last placeholder can be followed by nothing, and it takes the rest of the line
+            quote! {
+                let #local = ::aocutils::parse::FromInput::from_span(rest)?;
+            }
+        } else {
+            quote! {
+                let (#local, rest) = rest.split_once(#literal)?;
+                let #local = ::aocutils::parse::FromInput::from_span(#local)?;
+            }
+        });
+    }
+
+    let trailing = match pattern.literals.last() {
+        Some(literal) if literal.is_empty() && !pattern.placeholders.is_empty() => quote!(),
+        _ => quote! {
+            if !rest.is_empty() {
+                return ::std::result::Result::Err(rest.error("expected the end of the line"));
+            }
+        },
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::aocutils::parse::FromInput for #name #ty_generics #where_clause {
+            fn from_span(span: ::aocutils::parse::Span<'_>) -> ::aocutils::Result<Self> {
+                #(#steps)*
+                #trailing
+
+                ::std::result::Result::Ok(#name {
+                    #(#constructors,)*
+                })
+            }
+        }
+    })
+}
+
+/// Derive `aocutils::parse::FromInput` for a struct from a template of the line it's parsed from.
+///
+/// Each `{field}` in the pattern is parsed with that field's own `FromInput`, and `{field.0}`,
+/// `{field.1}`, ... fill in the elements of a tuple field. Any text between placeholders has to
+/// match exactly.
+///
+/// ```ignore
+/// #[derive(FromInput)]
+/// #[aoc(pattern = "p={position.0},{position.1} v={velocity.0},{velocity.1}")]
+/// struct Robot {
+///     position: (isize, isize),
+///     velocity: (isize, isize),
+/// }
+/// ```
+#[proc_macro_derive(FromInput, attributes(aoc))]
+pub fn from_input(input: TokenStream) -> TokenStream {
+    derive_from_input(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholder(field: &str, index: Option<usize>) -> Placeholder {
+        Placeholder {
+            field: field.to_string(),
+            index,
+        }
+    }
+
+    #[test]
+    fn test_parse_pattern() {
+        assert_eq!(
+            parse_pattern("p={position.0},{position.1} v={velocity}").unwrap(),
+            Pattern {
+                literals: vec!["p=".into(), ",".into(), " v=".into(), "".into()],
+                placeholders: vec![
+                    placeholder("position", Some(0)),
+                    placeholder("position", Some(1)),
+                    placeholder("velocity", None),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_pattern_errors() {
+        assert!(parse_pattern("{a}{b}").is_err());
+        assert!(parse_pattern("{a} {a}").is_err());
+        assert!(parse_pattern("{a.x}").is_err());
+        assert!(parse_pattern("{}").is_err());
+        assert!(parse_pattern("a}").is_err());
+        assert_eq!(
+            parse_pattern("p={position"),
+            Err("unmatched '{'".to_string())
+        );
+        assert_eq!(parse_pattern("{a} {"), Err("unmatched '{'".to_string()));
+    }
+}
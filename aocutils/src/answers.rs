@@ -0,0 +1,134 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use toml::Value;
+
+use crate::{Answers, Error, Result};
+
+/// Name of the file that records the accepted answers for a day's input
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The answers file sits next to the input it was recorded for, e.g. `2024/day07/inputs/answers.toml`
+pub fn answers_path(input_path: &Path) -> PathBuf {
+    input_path.with_file_name(ANSWERS_FILE)
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnswersFile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Value>,
+}
+
+/// Answers can be written as either numbers or strings, but are always compared as strings
+fn answer_to_string(contents: &str, part: &str, value: Value) -> Result<String> {
+    match value {
+        Value::String(s) => Ok(s),
+        Value::Integer(i) => Ok(i.to_string()),
+        _ => Err(Error::parse_at(
+            contents,
+            contents
+                .find(part)
+                .map_or("", |i| &contents[i..i + part.len()]),
+            format!("{} should be a string or an integer", part),
+        )),
+    }
+}
+
+/// Parse the contents of an answers file, e.g.
+///
+/// ```toml
+/// part1 = 3749
+/// part2 = "11387"
+/// ```
+pub fn parse_answers(contents: &str) -> Result<Answers> {
+    let file: AnswersFile = toml::from_str(contents).map_err(|e| {
+        let span = e.span().unwrap_or(0..0);
+        Error::parse_at(contents, &contents[span], e.message())
+    })?;
+
+    Ok(Answers {
+        part1: file
+            .part1
+            .map(|v| answer_to_string(contents, "part1", v))
+            .transpose()?,
+        part2: file
+            .part2
+            .map(|v| answer_to_string(contents, "part2", v))
+            .transpose()?,
+    })
+}
+
+/// Read the recorded answers, returns `None` if nothing has been recorded yet
+pub fn read_answers(path: &Path) -> Result<Option<Answers>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse_answers(&contents).map(Some),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn write_answers(path: &Path, answers: &Answers) -> Result<()> {
+    let file = AnswersFile {
+        part1: answers.part1.clone().map(Value::String),
+        part2: answers.part2.clone().map(Value::String),
+    };
+    let contents = toml::to_string(&file)
+        .map_err(|e| Error::invalid_state(format!("couldn't write answers: {}", e)))?;
+
+    Ok(fs::write(path, contents)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("part1 = 3749\npart2 = \"11387\"\n").unwrap(),
+            Answers {
+                part1: Some("3749".to_string()),
+                part2: Some("11387".to_string()),
+            }
+        );
+        assert_eq!(
+            parse_answers("part1 = \"50,50\"\n").unwrap(),
+            Answers {
+                part1: Some("50,50".to_string()),
+                part2: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_answers_invalid() {
+        match parse_answers("part1 = 1\npart2 = 1.5\n") {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 1)),
+            r => panic!("expected a parse error, got {:?}", r),
+        }
+        assert!(parse_answers("part3 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_write_answers() {
+        let path =
+            std::env::temp_dir().join(format!("aocutils-answers-{}.toml", std::process::id()));
+        let answers = Answers {
+            part1: Some("143".to_string()),
+            part2: None,
+        };
+
+        write_answers(&path, &answers).unwrap();
+        let read = read_answers(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(read, Some(answers));
+        assert_eq!(read_answers(&path).unwrap(), None);
+    }
+}
@@ -0,0 +1,109 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{solution::is_unsolved, Result, Solution};
+
+/// Summary of repeated timings of a single phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarise a set of samples, returns `None` if there aren't any
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        // nearest-rank percentiles, so every value is one that was actually measured
+        let percentile = |p: usize| sorted[(sorted.len() * p).div_ceil(100).max(1) - 1];
+
+        Some(Stats {
+            min: *sorted.first()?,
+            median: percentile(50),
+            p95: percentile(95),
+        })
+    }
+}
+
+/// Timings for each phase of a day, parts that are unsolved aren't timed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+fn time<T>(f: impl FnOnce() -> Result<T>) -> Result<(T, Duration)> {
+    let start = Instant::now();
+    let value = black_box(f()?);
+    Ok((value, start.elapsed()))
+}
+
+pub(crate) fn bench_solution<S: Solution>(input: &str, runs: usize) -> Result<Timings> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+
+    for _ in 0..runs.max(1) {
+        let (parsed, elapsed) = time(|| S::parse(black_box(input)))?;
+        parse.push(elapsed);
+
+        if !is_unsolved::<S::Part1>() {
+            part1.push(time(|| S::part1(&parsed))?.1);
+        }
+        if !is_unsolved::<S::Part2>() {
+            part2.push(time(|| S::part2(&parsed))?.1);
+        }
+    }
+
+    Ok(Timings {
+        parse: Stats::from_samples(&parse).expect("should have at least one run"),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = ms(&[
+            20, 1, 19, 2, 18, 3, 17, 4, 16, 5, 15, 6, 14, 7, 13, 8, 12, 9, 11, 10,
+        ]);
+
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(10),
+                p95: Duration::from_millis(19),
+            })
+        );
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let five = Duration::from_millis(5);
+
+        assert_eq!(
+            Stats::from_samples(&[five]),
+            Some(Stats {
+                min: five,
+                median: five,
+                p95: five,
+            })
+        );
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
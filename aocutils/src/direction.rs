@@ -0,0 +1,235 @@
+use std::str::FromStr;
+
+use crate::{Error, Point};
+
+/// One of the 4 orthogonal directions, with north being up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    /// Every direction, clockwise from north
+    pub const ALL: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    /// Parse an arrow (`^>v<`), compass point (`NESW`) or movement (`URDL`)
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' | 'U' => Some(Direction4::North),
+            '>' | 'E' | 'R' => Some(Direction4::East),
+            'v' | 'S' | 'D' => Some(Direction4::South),
+            '<' | 'W' | 'L' => Some(Direction4::West),
+            _ => None,
+        }
+    }
+
+    /// The arrow pointing this way, for drawing
+    pub fn arrow(self) -> char {
+        match self {
+            Direction4::North => '^',
+            Direction4::East => '>',
+            Direction4::South => 'v',
+            Direction4::West => '<',
+        }
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction4::East | Direction4::West)
+    }
+
+    /// The offset of a single step in this direction
+    pub fn to_point(self) -> Point {
+        match self {
+            Direction4::North => Point::new(0, -1),
+            Direction4::East => Point::new(1, 0),
+            Direction4::South => Point::new(0, 1),
+            Direction4::West => Point::new(-1, 0),
+        }
+    }
+
+    /// Move `p` one step in this direction
+    pub fn step(self, p: Point) -> Point {
+        p + self.to_point()
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next().and_then(Direction4::from_char), chars.next()) {
+            (Some(d), None) => Ok(d),
+            _ => Err(Error::parse(1, 1, s, "expected a direction")),
+        }
+    }
+}
+
+/// One of the 8 directions including diagonals, with north being up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Rotate 45 degrees clockwise
+    pub fn rotate_cw(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Rotate 45 degrees anticlockwise
+    pub fn rotate_ccw(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The offset of a single step in this direction
+    pub fn to_point(self) -> Point {
+        match self {
+            Direction8::North => Point::new(0, -1),
+            Direction8::NorthEast => Point::new(1, -1),
+            Direction8::East => Point::new(1, 0),
+            Direction8::SouthEast => Point::new(1, 1),
+            Direction8::South => Point::new(0, 1),
+            Direction8::SouthWest => Point::new(-1, 1),
+            Direction8::West => Point::new(-1, 0),
+            Direction8::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Move `p` one step in this direction
+    pub fn step(self, p: Point) -> Point {
+        p + self.to_point()
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(d: Direction4) -> Self {
+        Direction8::ALL[d as usize * 2]
+    }
+}
+
+impl FromStr for Direction8 {
+    type Err = Error;
+
+    /// Parse a compass point (e.g. `NE`), or anything a [`Direction4`] can be parsed from
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction8::NorthEast),
+            "SE" => Ok(Direction8::SouthEast),
+            "SW" => Ok(Direction8::SouthWest),
+            "NW" => Ok(Direction8::NorthWest),
+            _ => s.parse::<Direction4>().map(Direction8::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction4_parse() {
+        for (chars, expected) in [
+            ("^NU", Direction4::North),
+            (">ER", Direction4::East),
+            ("vSD", Direction4::South),
+            ("<WL", Direction4::West),
+        ] {
+            for c in chars.chars() {
+                assert_eq!(Direction4::from_char(c), Some(expected));
+                assert_eq!(c.to_string().parse::<Direction4>().unwrap(), expected);
+            }
+        }
+        assert_eq!(Direction4::from_char('x'), None);
+        assert!("NE".parse::<Direction4>().is_err());
+        assert!("".parse::<Direction4>().is_err());
+    }
+
+    #[test]
+    fn test_direction4_rotation() {
+        assert_eq!(Direction4::North.rotate_cw(), Direction4::East);
+        assert_eq!(Direction4::West.rotate_cw(), Direction4::North);
+        assert_eq!(Direction4::North.rotate_ccw(), Direction4::West);
+        assert_eq!(Direction4::East.opposite(), Direction4::West);
+
+        for d in Direction4::ALL {
+            assert_eq!(d.rotate_cw().rotate_ccw(), d);
+            assert_eq!(d.to_point(), -d.opposite().to_point());
+            assert_eq!(Direction4::from_char(d.arrow()), Some(d));
+        }
+    }
+
+    #[test]
+    fn test_direction4_step() {
+        let p = Point::new(2, 2);
+
+        assert_eq!(Direction4::North.step(p), Point::new(2, 1));
+        assert_eq!(Direction4::East.step(p), Point::new(3, 2));
+        assert_eq!(Direction4::South.step(p), Point::new(2, 3));
+        assert_eq!(Direction4::West.step(p), Point::new(1, 2));
+    }
+
+    #[test]
+    fn test_direction8() {
+        assert_eq!(Direction8::North.rotate_cw(), Direction8::NorthEast);
+        assert_eq!(Direction8::North.rotate_ccw(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthEast.opposite(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.step(Point::ORIGIN), Point::new(-1, 1));
+        assert_eq!(Direction8::from(Direction4::West), Direction8::West);
+        assert_eq!("NE".parse::<Direction8>().unwrap(), Direction8::NorthEast);
+        assert_eq!("v".parse::<Direction8>().unwrap(), Direction8::South);
+
+        for d in Direction4::ALL {
+            assert_eq!(Direction8::from(d).to_point(), d.to_point());
+            assert!(!Direction8::from(d).is_diagonal());
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    path::PathBuf,
+};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Everything that can go wrong while reading an input or solving a puzzle
+#[derive(Debug)]
+pub enum Error {
+    /// None of the candidate paths for an input exist
+    MissingInput { name: String, tried: Vec<PathBuf> },
+    /// The input exists but couldn't be read
+    Io(io::Error),
+    /// Part of the input couldn't be understood. `line` and `column` are 1-based and point at the start of `text`
+    Parse {
+        line: usize,
+        column: usize,
+        text: String,
+        message: String,
+    },
+    /// The puzzle got into a state the solution doesn't know how to handle
+    InvalidState(String),
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Create a parse error for `text`, which must be a slice of `input`, working out its line and column
+    pub fn parse_at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + text.len() <= input.len())
+            .expect("text should be a slice of the input");
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = input[line_start..offset].chars().count() + 1;
+
+        Error::parse(line, column, text, message)
+    }
+
+    pub fn invalid_state(message: impl Into<String>) -> Self {
+        Error::InvalidState(message.into())
+    }
+
+    /// For parse errors, the offending line of `input` with a caret under the bad text
+    pub fn snippet(&self, input: &str) -> Option<String> {
+        let Error::Parse {
+            line, column, text, ..
+        } = self
+        else {
+            return None;
+        };
+
+        let source = input.lines().nth(line.checked_sub(1)?)?;
+        let gutter = line.to_string();
+        let carets = "^".repeat(text.chars().count().max(1));
+
+        Some(format!(
+            "{} | {}\n{} | {}{}",
+            gutter,
+            source,
+            " ".repeat(gutter.len()),
+            " ".repeat(column.saturating_sub(1)),
+            carets
+        ))
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput { name, tried } => {
+                write!(f, "could not find input '{}', tried:", name)?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            Error::Io(e) => write!(f, "could not read input: {}", e),
+            Error::Parse {
+                line,
+                column,
+                text,
+                message,
+            } => write!(
+                f,
+                "{} at line {}, column {}: '{}'",
+                message, line, column, text
+            ),
+            Error::InvalidState(message) => write!(f, "invalid puzzle state: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_at() {
+        let input = "p=0,4 v=3,-3\np=6,x v=-1,-3";
+        let bad = &input[17..18];
+
+        match Error::parse_at(input, bad, "expected a number") {
+            Error::Parse {
+                line, column, text, ..
+            } => assert_eq!((line, column, text.as_str()), (2, 5, "x")),
+            e => panic!("expected a parse error, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_snippet() {
+        let input = "#..#\n#.x.#\n####";
+        let err = Error::parse(2, 3, "x", "unexpected item");
+
+        assert_eq!(err.snippet(input).unwrap(), "2 | #.x.#\n  |   ^");
+        assert_eq!(Error::invalid_state("no robot").snippet(input), None);
+    }
+}
@@ -0,0 +1,406 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{Error, Point, Result};
+
+/// Offsets of the 4 orthogonal neighbours, clockwise from up
+const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Offsets of all 8 neighbours, clockwise from up
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A dense 2D grid, indexed by `(x, y)` with `(0, 0)` at the top left
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid with every cell set to `value`. A grid without any cells has no rows or
+    /// columns either, so if either size is 0 both are.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        let (width, height) = match width == 0 || height == 0 {
+            true => (0, 0),
+            false => (width, height),
+        };
+
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells in row order. Panics if they don't make up whole rows. Without
+    /// any cells the grid is empty, whatever the width.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            cells.len().is_multiple_of(width),
+            "{} cells can't make rows of {}",
+            cells.len(),
+            width
+        );
+
+        if cells.is_empty() {
+            return Grid {
+                width: 0,
+                height: 0,
+                cells,
+            };
+        }
+
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parse a grid with a cell per char, failing if a char isn't recognised or the lines aren't all the same length
+    pub fn try_parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for line in input.lines() {
+            let expected = *width.get_or_insert(line.chars().count());
+            if line.chars().count() != expected {
+                return Err(Error::parse_at(
+                    input,
+                    line,
+                    format!("expected a row of {} cells", expected),
+                ));
+            }
+
+            for (i, c) in line.char_indices() {
+                cells.push(f(c).ok_or_else(|| {
+                    Error::parse_at(input, &line[i..i + c.len_utf8()], "unexpected cell")
+                })?);
+            }
+        }
+
+        Ok(Grid::from_vec(width.unwrap_or(0), cells))
+    }
+
+    /// Parse a grid with a cell per char, failing if the lines aren't all the same length
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self> {
+        Grid::try_parse(input, |c| Some(f(c)))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(|i| &mut self.cells[i])
+    }
+
+    /// Like [`Grid::get`], but for coordinates that might have gone negative
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        if self.in_bounds(x, y) {
+            self.get(x as usize, y as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_point(&self, p: Point) -> Option<&T> {
+        self.get_signed(p.x, p.y)
+    }
+
+    pub fn get_point_mut(&mut self, p: Point) -> Option<&mut T> {
+        let (x, y) = p.to_usize()?;
+        self.get_mut(x, y)
+    }
+
+    /// Move from `(x, y)` by `(dx, dy)`, returning `None` if that leaves the grid
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// Every cell along with its position, in row order
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, v)| ((i % self.width, i / self.width), v))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, v)| ((i % width, i / width), v))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, but there are no rows to give back anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Cells from `start` (inclusive), repeatedly stepping by `step` until leaving the grid
+    pub fn ray(&self, start: (usize, usize), step: (isize, isize)) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.get(start.0, start.1).map(|_| start), move |pos| {
+            self.offset(*pos, step)
+        })
+        .map(|(x, y)| &self[(x, y)])
+    }
+
+    /// Diagonals running down and to the right, starting from the bottom left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..self.width).map(|x| (x, 0)));
+
+        starts.map(|start| self.ray(start, (1, 1)))
+    }
+
+    /// Diagonals running down and to the left, starting from the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|x| (x, 0))
+            .chain((1..self.height).map(|y| (self.width - 1, y)));
+
+        starts.map(|start| self.ray(start, (-1, 1)))
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        pos: (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |d| self.offset(pos, *d))
+    }
+
+    /// Positions of the up/right/down/left neighbours of `pos` that are inside the grid
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(pos, &NEIGHBOURS4)
+    }
+
+    /// Positions of the neighbours of `pos`, including diagonals, that are inside the grid
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(pos, &NEIGHBOURS8)
+    }
+
+    /// Position of the first cell, in row order, that matches
+    pub fn find(&self, mut f: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, v)| f(v)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\ndef";
+
+    fn example() -> Grid<char> {
+        Grid::parse(EXAMPLE, |c| c).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.to_string(), EXAMPLE);
+
+        let digits = Grid::try_parse("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.row(1), &[3, 4]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        match Grid::parse("abc\nde\nfgh", |c| c) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 1)),
+            r => panic!("expected a parse error, got {:?}", r),
+        }
+        match Grid::try_parse("12\n3x", |c| c.to_digit(10)) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 2)),
+            r => panic!("expected a parse error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = example();
+
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert_eq!(grid.get(3, 1), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(0, 1), Some(&'d'));
+        assert_eq!(grid.get_point(Point::new(1, 0)), Some(&'b'));
+        assert_eq!(grid.get_point(Point::new(1, -1)), None);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (2, 1)), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = example();
+        let collect = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+
+        assert_eq!(
+            grid.rows()
+                .map(|r| r.iter().collect())
+                .collect::<Vec<String>>(),
+            vec!["abc", "def"]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|mut c| collect(&mut c))
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            vec!["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            vec!["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((1, 0)).collect::<Vec<_>>(),
+            vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_find_and_map() {
+        let grid = example();
+
+        assert_eq!(grid.find(|c| *c == 'e'), Some((1, 1)));
+        assert_eq!(grid.find(|c| *c == 'z'), None);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid = Grid::parse("", |c| c).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.to_string(), "");
+
+        // blank lines still make an empty grid
+        assert_eq!(Grid::parse("\n\n", |c| c).unwrap(), grid);
+
+        for grid in [
+            Grid::new(0, 3, '.'),
+            Grid::new(3, 0, '.'),
+            Grid::from_vec(4, vec![]),
+        ] {
+            assert_eq!((grid.width(), grid.height()), (0, 0));
+            assert_eq!(grid.rows().count(), 0);
+            assert_eq!(grid.columns().count(), 0);
+            assert_eq!(grid.diagonals().count(), 0);
+            assert_eq!(grid.anti_diagonals().count(), 0);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, Grid, Result};
+
+/// Environment variable pointing at a directory of inputs to use instead of each day's `inputs` directory
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Every path an input could be at, in the order they should be checked.
+///
+/// `crate_dir` is the day crate's manifest directory (e.g. `2024/day07`). An `override_dir`, which is usually
+/// [`INPUT_DIR_VAR`], is checked first and is laid out like the workspace (`$AOC_INPUT_DIR/2024/day07/input.txt`).
+/// There's no fallback to a flat `$AOC_INPUT_DIR/input.txt`, which would be used for every day that doesn't have its
+/// own input.
+pub fn input_paths(crate_dir: &Path, override_dir: Option<&Path>, name: &str) -> Vec<PathBuf> {
+    let file_name = format!("{}.txt", name);
+    let mut paths = Vec::new();
+
+    if let Some(override_dir) = override_dir {
+        // the last two components of the crate dir are the year and day
+        let components = crate_dir.components().rev().take(2).collect::<Vec<_>>();
+        if let [day, year] = components.as_slice() {
+            paths.push(override_dir.join(year).join(day).join(&file_name));
+        }
+    }
+
+    let inputs_dir = crate_dir.join("inputs");
+    paths.push(inputs_dir.join(&file_name));
+    // some older days saved their input without an extension
+    paths.push(inputs_dir.join(name));
+
+    paths
+}
+
+/// Find the first of [`input_paths`] that exists, using [`INPUT_DIR_VAR`] if it's set
+pub fn find_input(crate_dir: &Path, name: &str) -> Result<PathBuf> {
+    let override_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    let tried = input_paths(crate_dir, override_dir.as_deref(), name);
+
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(Error::MissingInput {
+            name: name.to_string(),
+            tried,
+        }),
+    }
+}
+
+pub fn read_input_in(crate_dir: &Path, name: &str) -> Result<String> {
+    Ok(fs::read_to_string(find_input(crate_dir, name)?)?
+        .trim()
+        .to_string())
+}
+
+pub fn read_input_lines_in(crate_dir: &Path, name: &str) -> Result<Vec<String>> {
+    Ok(read_input_in(crate_dir, name)?
+        .split("\n")
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>())
+}
+
+pub fn read_input_grid_in(crate_dir: &Path, name: &str) -> Result<Grid<char>> {
+    Grid::parse(&read_input_in(crate_dir, name)?, |c| c)
+}
+
+pub fn read_input_lines_whitespace_in(crate_dir: &Path, name: &str) -> Result<Vec<Vec<String>>> {
+    Ok(read_input_in(crate_dir, name)?
+        .split("\n")
+        .map(|v| {
+            v.split_whitespace()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<Vec<_>>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_paths() {
+        assert_eq!(
+            input_paths(Path::new("/aoc/2024/day07"), None, "example"),
+            vec![
+                PathBuf::from("/aoc/2024/day07/inputs/example.txt"),
+                PathBuf::from("/aoc/2024/day07/inputs/example"),
+            ]
+        );
+        assert_eq!(
+            input_paths(
+                Path::new("/aoc/2024/day07"),
+                Some(Path::new("/inputs")),
+                "input"
+            ),
+            vec![
+                PathBuf::from("/inputs/2024/day07/input.txt"),
+                PathBuf::from("/aoc/2024/day07/inputs/input.txt"),
+                PathBuf::from("/aoc/2024/day07/inputs/input"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_input_missing() {
+        let err = find_input(Path::new("/does/not/exist"), "input").unwrap_err();
+
+        assert!(err.to_string().contains("/does/not/exist/inputs/input.txt"));
+        match err {
+            Error::MissingInput { tried, .. } => {
+                let override_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+                assert_eq!(
+                    tried,
+                    input_paths(
+                        Path::new("/does/not/exist"),
+                        override_dir.as_deref(),
+                        "input"
+                    )
+                )
+            }
+            e => panic!("expected a missing input error, got {:?}", e),
+        }
+    }
+}
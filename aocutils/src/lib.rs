@@ -0,0 +1,111 @@
+extern crate self as aocutils;
+
+mod answers;
+mod bench;
+mod direction;
+mod error;
+mod grid;
+mod input;
+pub mod parse;
+mod point;
+mod puzzle;
+#[cfg(feature = "render")]
+pub mod render;
+mod search;
+mod solution;
+#[cfg(feature = "viz")]
+pub mod viz;
+
+pub use answers::{answers_path, parse_answers, read_answers, write_answers, ANSWERS_FILE};
+pub use bench::{Stats, Timings};
+pub use direction::{Direction4, Direction8};
+pub use error::{Error, Result};
+pub use grid::Grid;
+pub use input::{
+    find_input, input_paths, read_input_grid_in, read_input_in, read_input_lines_in,
+    read_input_lines_whitespace_in, INPUT_DIR_VAR,
+};
+pub use point::Point;
+pub use puzzle::{parse_examples, read_examples_in, run_example, Example, Examples, PUZZLE_FILE};
+pub use search::{astar, bfs, dijkstra, dijkstra_all, Cost, ShortestPaths};
+pub use solution::{Answers, Day, Registry, Solution, Unsolved};
+
+/// Read `inputs/<name>.txt` relative to the calling crate, regardless of the current directory
+#[macro_export]
+macro_rules! read_input {
+    ($name:expr) => {
+        $crate::read_input_in(::std::path::Path::new(env!("CARGO_MANIFEST_DIR")), $name)
+    };
+}
+
+/// Like [`read_input!`], split into lines
+#[macro_export]
+macro_rules! read_input_lines {
+    ($name:expr) => {
+        $crate::read_input_lines_in(::std::path::Path::new(env!("CARGO_MANIFEST_DIR")), $name)
+    };
+}
+
+/// Like [`read_input!`], parsed into a [`Grid`] of chars
+#[macro_export]
+macro_rules! read_input_grid {
+    ($name:expr) => {
+        $crate::read_input_grid_in(::std::path::Path::new(env!("CARGO_MANIFEST_DIR")), $name)
+    };
+}
+
+/// Like [`read_input!`], split into lines of whitespace separated words
+#[macro_export]
+macro_rules! read_input_lines_whitespace {
+    ($name:expr) => {
+        $crate::read_input_lines_whitespace_in(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+            $name,
+        )
+    };
+}
+
+/// Generate a test for each part of a solution that checks it against the example in the crate's `puzzle.md`.
+///
+/// Parts can be listed to only test those, e.g. when part one's example needs different settings to the real input.
+/// `puzzle.md` is gitignored, so the tests are ignored by default rather than passing without checking anything, and
+/// are run with `cargo test -- --ignored` once the puzzle has been saved. They fail if there's no example to check.
+///
+/// ```ignore
+/// aocutils::examples!(Day07);
+/// aocutils::examples!(Day18, part2);
+/// ```
+#[macro_export]
+macro_rules! examples {
+    (@test $day:ty, part1) => {
+        $crate::examples!(@test $day, part1, 1);
+    };
+    (@test $day:ty, part2) => {
+        $crate::examples!(@test $day, part2, 2);
+    };
+    (@test $day:ty, $part:ident, $n:literal) => {
+        #[test]
+        #[ignore = "needs the puzzle description saved as puzzle.md"]
+        fn $part() {
+            let crate_dir = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+            let puzzle = crate_dir.join($crate::PUZZLE_FILE);
+            assert!(puzzle.exists(), "{} hasn't been saved", puzzle.display());
+
+            match $crate::run_example::<$day>(crate_dir, $n).unwrap() {
+                Some((expected, actual)) => assert_eq!(actual, expected),
+                None => panic!("no example to check part {} against, or it's unsolved", $n),
+            }
+        }
+    };
+    ($day:ty) => {
+        $crate::examples!($day, part1, part2);
+    };
+    ($day:ty, $($part:ident),+) => {
+        mod examples {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $($crate::examples!(@test $day, $part);)+
+        }
+    };
+}
@@ -0,0 +1,404 @@
+//! Small building blocks for parsing puzzle inputs.
+//!
+//! Everything works on a [`Span`], a slice of the input that remembers the whole input so that a
+//! failure anywhere deep inside a parser still reports the line and column it happened at.
+//!
+//! ```
+//! use aocutils::parse::Span;
+//!
+//! let equations = Span::new("190: 10 19\n3267: 81 40 27")
+//!     .map_lines(|line| {
+//!         let (result, numbers) = line.split_once(": ")?;
+//!         Ok((result.number::<i64>()?, numbers.words(|n| n.number::<i64>())?))
+//!     })
+//!     .unwrap();
+//!
+//! assert_eq!(equations[1], (3267, vec![81, 40, 27]));
+//! ```
+//!
+//! Structs that are parsed from a line template can derive [`FromInput`] instead:
+//!
+//! ```
+//! use aocutils::parse::FromInput;
+//!
+//! #[derive(FromInput)]
+//! #[aoc(pattern = "move {quantity} from {from} to {to}")]
+//! struct Move {
+//!     quantity: usize,
+//!     from: usize,
+//!     to: usize,
+//! }
+//!
+//! let moves = Move::from_input("move 1 from 2 to 1\nmove 3 from 1 to 3").unwrap();
+//! assert_eq!(moves[1].quantity, 3);
+//! ```
+//!
+//! Mistakes in the pattern, like a `{` that's never closed, are compile errors:
+//!
+//! ```compile_fail
+//! use aocutils::parse::FromInput;
+//!
+//! #[derive(FromInput)]
+//! #[aoc(pattern = "move {quantity} from {from")]
+//! struct Move {
+//!     quantity: usize,
+//!     from: usize,
+//! }
+//! ```
+
+use std::str::FromStr;
+
+use crate::{Error, Result};
+
+pub use aocutils_derive::FromInput;
+
+/// A slice of a puzzle input, along with the whole input it came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    input: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// Start parsing a whole input
+    pub fn new(input: &'a str) -> Self {
+        Span { input, text: input }
+    }
+
+    /// Narrow down to `text`, which must be a slice of this span
+    fn sub(self, text: &'a str) -> Self {
+        Span {
+            input: self.input,
+            text,
+        }
+    }
+
+    pub fn as_str(self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// A parse error pointing at this span
+    pub fn error(self, message: impl Into<String>) -> Error {
+        Error::parse_at(self.input, self.text, message)
+    }
+
+    pub fn trim(self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    pub fn lines(self) -> impl Iterator<Item = Span<'a>> {
+        self.text.lines().map(move |line| self.sub(line))
+    }
+
+    /// Groups of lines separated by a blank line
+    pub fn blocks(self) -> impl Iterator<Item = Span<'a>> {
+        self.text
+            .trim_end_matches('\n')
+            .split("\n\n")
+            .map(move |block| self.sub(block))
+    }
+
+    pub fn split(self, separator: &'a str) -> impl Iterator<Item = Span<'a>> {
+        self.text.split(separator).map(move |part| self.sub(part))
+    }
+
+    pub fn split_whitespace(self) -> impl Iterator<Item = Span<'a>> {
+        self.text.split_whitespace().map(move |word| self.sub(word))
+    }
+
+    /// Split around the first `separator`, which must be there
+    pub fn split_once(self, separator: &str) -> Result<(Span<'a>, Span<'a>)> {
+        match self.text.split_once(separator) {
+            Some((before, after)) => Ok((self.sub(before), self.sub(after))),
+            None => Err(self.error(format!("expected '{}'", separator))),
+        }
+    }
+
+    /// Split a `key: value` style record, trimming both halves
+    pub fn key_value(self, separator: &str) -> Result<(Span<'a>, Span<'a>)> {
+        let (key, value) = self.split_once(separator)?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// Expect the span to start with `tag`, and return the rest of it
+    pub fn tag(self, tag: &str) -> Result<Span<'a>> {
+        match self.text.strip_prefix(tag) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => {
+                // point at as much of the text as the tag would have covered
+                let end = self
+                    .text
+                    .char_indices()
+                    .nth(tag.chars().count())
+                    .map_or(self.text.len(), |(i, _)| i);
+
+                Err(self
+                    .sub(&self.text[..end])
+                    .error(format!("expected '{}'", tag)))
+            }
+        }
+    }
+
+    /// Parse the whole span, e.g. as a signed integer
+    pub fn number<T: FromStr>(self) -> Result<T> {
+        self.text
+            .parse()
+            .map_err(|_| self.error("expected a number"))
+    }
+
+    /// Parse each item of a list separated by `separator`, such as `", "`. Items are trimmed first.
+    pub fn list<T>(
+        self,
+        separator: &'a str,
+        f: impl FnMut(Span<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        self.split(separator).map(Span::trim).map(f).collect()
+    }
+
+    /// Parse each whitespace separated word
+    pub fn words<T>(self, f: impl FnMut(Span<'a>) -> Result<T>) -> Result<Vec<T>> {
+        self.split_whitespace().map(f).collect()
+    }
+
+    /// Parse each line
+    pub fn map_lines<T>(self, f: impl FnMut(Span<'a>) -> Result<T>) -> Result<Vec<T>> {
+        self.lines().map(f).collect()
+    }
+
+    /// Parse each block of lines separated by a blank line
+    pub fn map_blocks<T>(self, f: impl FnMut(Span<'a>) -> Result<T>) -> Result<Vec<T>> {
+        self.blocks().map(f).collect()
+    }
+
+    /// Every integer in the span, ignoring whatever is around them. A `-` right before a number
+    /// makes it negative, unless it comes straight after a letter or digit, so that ranges like
+    /// `2-4` are two numbers.
+    pub fn integers<T: FromStr>(self) -> Result<Vec<T>> {
+        let bytes = self.text.as_bytes();
+        let mut numbers = Vec::new();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let negative = bytes[i] == b'-'
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && !(i > 0 && bytes[i - 1].is_ascii_alphanumeric());
+
+            if !negative && !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+
+            numbers.push(self.sub(&self.text[start..i]).number()?);
+        }
+
+        Ok(numbers)
+    }
+
+    /// Parse the whole span as a [`FromInput`] type
+    pub fn parse<T: FromInput>(self) -> Result<T> {
+        T::from_span(self)
+    }
+}
+
+/// Something that can be parsed from a piece of input, usually a single line.
+///
+/// Numbers, strings and single characters are parsed from the whole span, and a `Vec` from whitespace
+/// separated words. Structs can derive it from a pattern, see [`aocutils_derive::FromInput`].
+pub trait FromInput: Sized {
+    fn from_span(span: Span<'_>) -> Result<Self>;
+
+    /// Parse a line on its own
+    fn from_line(line: &str) -> Result<Self> {
+        Self::from_span(Span::new(line))
+    }
+
+    /// Parse each line of a whole input
+    fn from_input(input: &str) -> Result<Vec<Self>> {
+        Span::new(input).map_lines(Self::from_span)
+    }
+}
+
+macro_rules! from_input_number {
+    ($($t:ty),*) => {
+        $(impl FromInput for $t {
+            fn from_span(span: Span<'_>) -> Result<Self> {
+                span.number()
+            }
+        })*
+    };
+}
+
+from_input_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl FromInput for String {
+    fn from_span(span: Span<'_>) -> Result<Self> {
+        Ok(span.as_str().to_string())
+    }
+}
+
+impl FromInput for char {
+    fn from_span(span: Span<'_>) -> Result<Self> {
+        let mut chars = span.as_str().chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(span.error("expected a single character")),
+        }
+    }
+}
+
+impl<T: FromInput> FromInput for Vec<T> {
+    fn from_span(span: Span<'_>) -> Result<Self> {
+        span.words(T::from_span)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(e: Error) -> (usize, usize, String) {
+        match e {
+            Error::Parse {
+                line, column, text, ..
+            } => (line, column, text),
+            e => panic!("expected a parse error, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_numbers_and_lists() {
+        let span = Span::new("-12, 5,+7");
+
+        assert_eq!(span.list(",", |n| n.number::<i32>()).unwrap(), [-12, 5, 7]);
+        assert_eq!(
+            Span::new("1 2  3\n4").words(Span::number::<u8>).unwrap(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(
+            position(
+                Span::new("1 2\n3 x")
+                    .map_lines(|l| l.words(Span::number::<u8>))
+                    .unwrap_err()
+            ),
+            (2, 3, "x".to_string())
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks = Span::new("a\nb\n\nc\n\n")
+            .map_blocks(|block| Ok(block.lines().count()))
+            .unwrap();
+
+        assert_eq!(blocks, [2, 1]);
+    }
+
+    #[test]
+    fn test_tags_and_records() {
+        let line = Span::new("Button A: X+94, Y+34");
+        let (key, value) = line.key_value(":").unwrap();
+        let (x, y) = value.split_once(", ").unwrap();
+
+        assert_eq!(key.as_str(), "Button A");
+        assert_eq!(x.tag("X+").unwrap().number::<i32>().unwrap(), 94);
+        assert_eq!(
+            position(y.tag("X+").unwrap_err()),
+            (1, 17, "Y+".to_string())
+        );
+        assert_eq!(
+            position(y.tag("Y+34 and").unwrap_err()),
+            (1, 17, "Y+34".to_string())
+        );
+        assert_eq!(
+            position(x.split_once("=").unwrap_err()),
+            (1, 11, "X+94".to_string())
+        );
+    }
+
+    #[derive(Debug, PartialEq, Eq, FromInput)]
+    #[aoc(pattern = "Sensor at x={sensor.0}, y={sensor.1}: {name} sees {beacons}")]
+    struct Sensor {
+        sensor: (i32, i32),
+        name: String,
+        beacons: Vec<u8>,
+    }
+
+    #[test]
+    fn test_derive_from_input() {
+        assert_eq!(
+            Sensor::from_line("Sensor at x=2, y=-18: s sees 1 2 3").unwrap(),
+            Sensor {
+                sensor: (2, -18),
+                name: "s".to_string(),
+                beacons: vec![1, 2, 3],
+            }
+        );
+
+        let input = "Sensor at x=2, y=18: s sees 1\nSensor at x=2, y=18: s sees 1 x";
+        assert_eq!(
+            position(Sensor::from_input(input).unwrap_err()),
+            (2, 31, "x".to_string())
+        );
+        assert_eq!(
+            position(Sensor::from_line("Sensor at x=2; y=18: s sees 1").unwrap_err()),
+            (1, 13, "2; y=18: s sees 1".to_string())
+        );
+        assert_eq!(
+            position(Sensor::from_line("Sensor at y=2").unwrap_err()),
+            (1, 1, "Sensor at y=".to_string())
+        );
+    }
+
+    #[derive(Debug, PartialEq, Eq, FromInput)]
+    #[aoc(pattern = "[{c}]")]
+    struct Crate {
+        c: char,
+    }
+
+    #[test]
+    fn test_derive_trailing_text() {
+        assert_eq!(Crate::from_line("[A]").unwrap(), Crate { c: 'A' });
+        assert_eq!(
+            position(Crate::from_line("[A]]").unwrap_err()),
+            (1, 4, "]".to_string())
+        );
+        assert_eq!(
+            position(Crate::from_line("[AB]").unwrap_err()),
+            (1, 2, "AB".to_string())
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        let span = Span::new("p=0,4 v=3,-3 x-y 12a-");
+
+        assert_eq!(span.integers::<i64>().unwrap(), [0, 4, 3, -3, 12]);
+        assert_eq!(
+            Span::new("2-4,6-8").integers::<i64>().unwrap(),
+            [2, 4, 6, 8]
+        );
+        assert_eq!(
+            Span::new("2-4,6-8").integers::<u32>().unwrap(),
+            [2, 4, 6, 8]
+        );
+        assert_eq!(
+            Span::new("-2--4 a-1 (-3)").integers::<i64>().unwrap(),
+            [-2, -4, 1, -3]
+        );
+        assert_eq!(
+            position(Span::new("ok 1\nbig 300").integers::<u8>().unwrap_err()),
+            (2, 5, "300".to_string())
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position (or offset) on a 2D plane, with `y` increasing downwards to match puzzle inputs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    /// Convert grid coordinates, returning `None` if they are too big to be signed
+    pub fn from_usize((x, y): (usize, usize)) -> Option<Self> {
+        Some(Point::new(x.try_into().ok()?, y.try_into().ok()?))
+    }
+
+    /// Convert to grid coordinates, returning `None` if either is negative
+    pub fn to_usize(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+
+    /// Distance moving only horizontally and vertically
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance when diagonal moves are allowed too
+    pub fn chebyshev(self, other: Point) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<Point> for (isize, isize) {
+    fn from(p: Point) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: isize) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point::new(3, -2);
+
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(-p, Point::new(-3, 2));
+        assert_eq!(p * 3, Point::new(9, -6));
+
+        p += Point::new(-3, 2);
+        assert_eq!(p, Point::ORIGIN);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, 1);
+        let b = Point::new(4, -3);
+
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(b.manhattan(a), 7);
+    }
+
+    #[test]
+    fn test_usize_conversion() {
+        assert_eq!(Point::from_usize((2, 5)), Some(Point::new(2, 5)));
+        assert_eq!(Point::from_usize((usize::MAX, 0)), None);
+        assert_eq!(Point::new(2, 5).to_usize(), Some((2, 5)));
+        assert_eq!(Point::new(-1, 5).to_usize(), None);
+    }
+}
@@ -0,0 +1,244 @@
+use std::{fs, io, path::Path};
+
+use crate::{solution::is_unsolved, Result, Solution};
+
+/// Name of the locally saved puzzle description, kept out of git
+pub const PUZZLE_FILE: &str = "puzzle.md";
+
+const PART_TWO_MARKER: &str = "--- Part Two ---";
+
+/// An example input from the puzzle description along with the answer it should give
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: String,
+}
+
+/// The examples for each part of a puzzle, parts that don't have one (e.g. part two hasn't been unlocked) are `None`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub part1: Option<Example>,
+    pub part2: Option<Example>,
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_tag = false;
+
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+
+    out
+}
+
+/// Every code block in the text along with its offset, either `<pre><code>` blocks from the puzzle's HTML or fenced
+/// markdown blocks
+fn code_blocks(text: &str) -> Vec<(usize, String)> {
+    let mut blocks = Vec::new();
+
+    let mut rest = 0;
+    while let Some(start) = text[rest..].find("<pre><code>").map(|i| i + rest) {
+        let content_start = start + "<pre><code>".len();
+        let Some(end) = text[content_start..].find("</code></pre>") else {
+            break;
+        };
+        let content = &text[content_start..content_start + end];
+        blocks.push((start, decode_entities(&strip_tags(content))));
+        rest = content_start + end;
+    }
+
+    let mut rest = 0;
+    while let Some(start) = text[rest..].find("```").map(|i| i + rest) {
+        // skip the rest of the opening line, which could have a language
+        let Some(content_start) = text[start..].find('\n').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = text[content_start..].find("```") else {
+            break;
+        };
+        blocks.push((start, text[content_start..content_start + end].to_string()));
+        rest = content_start + end + 3;
+    }
+
+    blocks.sort_by_key(|(offset, _)| *offset);
+    blocks
+        .into_iter()
+        .map(|(offset, block)| (offset, block.trim_end().to_string()))
+        .filter(|(_, block)| !block.is_empty())
+        .collect()
+}
+
+/// Offset of the last highlighted answer in the text, which AoC writes as `<code><em>42</em></code>` (or the other
+/// way round), or `` **`42`** `` once converted to markdown
+fn last_answer(text: &str) -> Option<(usize, String)> {
+    [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+        ("**`", "`**"),
+        ("`**", "**`"),
+    ]
+    .iter()
+    .filter_map(|(open, close)| {
+        let start = text.rfind(open)?;
+        let content_start = start + open.len();
+        let end = text[content_start..].find(close)?;
+        let answer = decode_entities(&strip_tags(&text[content_start..content_start + end]));
+
+        Some((start, answer))
+    })
+    .max_by_key(|(start, _)| *start)
+}
+
+/// Find the examples in a puzzle description.
+///
+/// The answer for each part is the last highlighted value in that part's section, and its example is the last code
+/// block before the answer. Part two often reuses part one's example, so that can be from the first section.
+pub fn parse_examples(puzzle: &str) -> Examples {
+    let blocks = code_blocks(puzzle);
+    let example_before = |offset: usize| {
+        blocks
+            .iter()
+            .rev()
+            .find(|(start, _)| *start < offset)
+            .map(|(_, block)| block.clone())
+    };
+
+    let (part1_text, part2_offset) = match puzzle.find(PART_TWO_MARKER) {
+        Some(i) => (&puzzle[..i], Some(i)),
+        None => (puzzle, None),
+    };
+
+    let part1 = last_answer(part1_text).and_then(|(offset, answer)| {
+        Some(Example {
+            input: example_before(offset)?,
+            answer,
+        })
+    });
+    let part2 = part2_offset.and_then(|part2_offset| {
+        let (offset, answer) = last_answer(&puzzle[part2_offset..])?;
+
+        Some(Example {
+            input: example_before(part2_offset + offset)?,
+            answer,
+        })
+    });
+
+    Examples { part1, part2 }
+}
+
+/// Read the examples from the day crate's `puzzle.md`, returns `None` if it hasn't been saved
+pub fn read_examples_in(crate_dir: &Path) -> Result<Option<Examples>> {
+    match fs::read_to_string(crate_dir.join(PUZZLE_FILE)) {
+        Ok(puzzle) => Ok(Some(parse_examples(&puzzle))),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Run a part of a solution on its example, returning the expected and actual answers.
+///
+/// Returns `None` if there's nothing to check, because the puzzle hasn't been saved, it has no example for that part,
+/// or the part is unsolved.
+pub fn run_example<S: Solution>(crate_dir: &Path, part: u8) -> Result<Option<(String, String)>> {
+    let Some(examples) = read_examples_in(crate_dir)? else {
+        return Ok(None);
+    };
+
+    match part {
+        1 if !is_unsolved::<S::Part1>() => examples
+            .part1
+            .map(|e| Ok((e.answer, S::part1(&S::parse(&e.input)?)?.to_string())))
+            .transpose(),
+        2 if !is_unsolved::<S::Part2>() => examples
+            .part2
+            .map(|e| Ok((e.answer, S::part2(&S::parse(&e.input)?)?.to_string())))
+            .transpose(),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "<article><h2>--- Day 7: Bridge Repair ---</h2>
+<p>For example:</p>
+<pre><code>190: 10 19
+3267: 81 40 27
+83: 17 5
+</code></pre>
+<p>Only <code>190</code> can be made, giving a total of <code><em>190</em></code>.</p>
+</article>
+<article><h2 id=\"part2\">--- Part Two ---</h2>
+<p>Now the total is <code><em>3457</em></code>.</p>
+</article>";
+
+    #[test]
+    fn test_parse_examples() {
+        let example = "190: 10 19\n3267: 81 40 27\n83: 17 5".to_string();
+
+        assert_eq!(
+            parse_examples(PUZZLE),
+            Examples {
+                part1: Some(Example {
+                    input: example.clone(),
+                    answer: "190".to_string()
+                }),
+                part2: Some(Example {
+                    input: example,
+                    answer: "3457".to_string()
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_examples_part1_only() {
+        let puzzle = &PUZZLE[..PUZZLE.find("<article><h2 id").unwrap()];
+
+        assert!(parse_examples(puzzle).part1.is_some());
+        assert_eq!(parse_examples(puzzle).part2, None);
+    }
+
+    #[test]
+    fn test_parse_examples_markdown() {
+        let puzzle = "For example:\n\n```\n#.#\n&lt;.&gt;\n```\n\nThere are **`2`** walls.\n\n\
+                      --- Part Two ---\n\nWith this example:\n\n```text\n##\n```\n\nThe answer is `**4**`.\n";
+
+        assert_eq!(
+            parse_examples(puzzle),
+            Examples {
+                part1: Some(Example {
+                    input: "#.#\n&lt;.&gt;".to_string(),
+                    answer: "2".to_string()
+                }),
+                part2: Some(Example {
+                    input: "##".to_string(),
+                    answer: "4".to_string()
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_code_blocks_decode_html() {
+        assert_eq!(
+            code_blocks("<pre><code>&lt;<em>x</em>&gt; &amp;\n</code></pre>"),
+            vec![(0, "<x> &".to_string())]
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! Render grids and text frames as images, and save them as PNG or PPM files or a looping GIF.
+//!
+//! Colours are the same [`Color`]s used by the terminal [`Palette`], with the named ones drawn in
+//! the usual xterm shades.
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::{
+    viz::{Color, Palette},
+    Grid,
+};
+
+/// The colour of anything a palette doesn't have a colour for
+pub const BACKGROUND: Color = Color::Black;
+
+/// The red, green and blue values for `colour`
+pub fn rgb(colour: Color) -> [u8; 3] {
+    match colour {
+        Color::Black => [0, 0, 0],
+        Color::Red => [205, 0, 0],
+        Color::Green => [0, 205, 0],
+        Color::Yellow => [205, 205, 0],
+        Color::Blue => [0, 0, 238],
+        Color::Magenta => [205, 0, 205],
+        Color::Cyan => [0, 205, 205],
+        Color::White => [229, 229, 229],
+        Color::BrightBlack => [127, 127, 127],
+        Color::BrightRed => [255, 0, 0],
+        Color::BrightGreen => [0, 255, 0],
+        Color::BrightYellow => [255, 255, 0],
+        Color::BrightBlue => [92, 92, 255],
+        Color::BrightMagenta => [255, 0, 255],
+        Color::BrightCyan => [0, 255, 255],
+        Color::BrightWhite => [255, 255, 255],
+        Color::TrueColor { r, g, b } => [r, g, b],
+    }
+}
+
+/// An image made of RGB pixels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Image {
+    /// An image filled with `colour`
+    pub fn new(width: usize, height: usize, colour: Color) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![rgb(colour); width * height],
+        }
+    }
+
+    /// Draw each cell of `grid` as a `scale` by `scale` square, in the colour `colour` picks for it
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, mut colour: impl FnMut(&T) -> Color) -> Self {
+        let mut image = Image::new(grid.width() * scale, grid.height() * scale, BACKGROUND);
+
+        for ((x, y), cell) in grid.iter() {
+            image.fill_cell(x, y, scale, rgb(colour(cell)));
+        }
+
+        image
+    }
+
+    /// Draw a text frame, such as one from an [`Animation`](crate::viz::Animation), with each
+    /// character as a `scale` by `scale` square in its colour from `palette`. Characters without a
+    /// colour are left as [`BACKGROUND`], and shorter lines are padded out with it.
+    pub fn from_text(frame: &str, palette: &Palette, scale: usize) -> Self {
+        let lines = frame.lines().collect::<Vec<_>>();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut image = Image::new(width * scale, lines.len() * scale, BACKGROUND);
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if let Some(colour) = palette.get(c) {
+                    image.fill_cell(x, y, scale, rgb(colour));
+                }
+            }
+        }
+
+        image
+    }
+
+    fn fill_cell(&mut self, x: usize, y: usize, scale: usize, colour: [u8; 3]) {
+        for py in y * scale..(y + 1) * scale {
+            let row = py * self.width;
+            self.pixels[row + x * scale..row + (x + 1) * scale].fill(colour);
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<[u8; 3]> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Crop or pad the image to `width` by `height`, keeping the top left corner where it is
+    pub fn resize(&self, width: usize, height: usize) -> Self {
+        let mut resized = Image::new(width, height, BACKGROUND);
+
+        for y in 0..height.min(self.height) {
+            let columns = width.min(self.width);
+            resized.pixels[y * width..y * width + columns]
+                .copy_from_slice(&self.pixels[y * self.width..y * self.width + columns]);
+        }
+
+        resized
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    /// Write as a binary PPM, which almost anything can open and is easy to read back in a test
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())
+    }
+
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.bytes())?;
+        Ok(writer.finish()?)
+    }
+
+    /// Save as a PNG or PPM, going by the extension of `path`
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let format = extension(path);
+        if format != "png" && format != "ppm" {
+            return Err(unsupported(path));
+        }
+
+        let mut out = BufWriter::new(File::create(path)?);
+        match format.as_str() {
+            "png" => self.write_png(&mut out)?,
+            _ => self.write_ppm(&mut out)?,
+        }
+
+        out.flush()
+    }
+}
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+fn unsupported(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!(
+            "can't save '{}', images can be saved as .png, .ppm or .gif",
+            path.display()
+        ),
+    )
+}
+
+fn gif_error(e: gif::EncodingError) -> io::Error {
+    match e {
+        gif::EncodingError::Io(e) => e,
+        e => io::Error::new(io::ErrorKind::InvalidData, e),
+    }
+}
+
+/// Write `frames` as a looping GIF, showing each for `1 / fps` seconds.
+///
+/// Every frame is cropped or padded to the size of the first one, and can have at most 256
+/// different colours.
+pub fn write_gif(
+    out: impl Write,
+    frames: impl IntoIterator<Item = Image>,
+    fps: f64,
+) -> io::Result<()> {
+    let mut frames = frames.into_iter();
+    let Some(first) = frames.next() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "a GIF needs at least one frame",
+        ));
+    };
+
+    let (width, height) = (first.width, first.height);
+    let too_big = || {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{}x{} is too big for a GIF", width, height),
+        )
+    };
+
+    let mut encoder = gif::Encoder::new(
+        out,
+        u16::try_from(width).map_err(|_| too_big())?,
+        u16::try_from(height).map_err(|_| too_big())?,
+        &[],
+    )
+    .map_err(gif_error)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(gif_error)?;
+
+    // GIF delays are in hundredths of a second
+    let delay = (100.0 / fps).round().clamp(1.0, u16::MAX as f64) as u16;
+
+    for image in std::iter::once(first).chain(frames) {
+        let image = match (image.width, image.height) == (width, height) {
+            true => image,
+            false => image.resize(width, height),
+        };
+
+        // the frames are drawn from a handful of palette colours, so each frame gets its own
+        // exact palette rather than being quantised
+        let mut indices = HashMap::new();
+        let mut palette = Vec::new();
+        let mut buffer = Vec::with_capacity(image.pixels.len());
+
+        for pixel in &image.pixels {
+            let next = indices.len();
+            let index = *indices.entry(*pixel).or_insert_with(|| {
+                palette.extend(pixel);
+                next
+            });
+
+            buffer.push(u8::try_from(index).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "a GIF frame can have at most 256 colours",
+                )
+            })?);
+        }
+
+        let frame = gif::Frame {
+            delay,
+            width: width as u16,
+            height: height as u16,
+            palette: Some(palette),
+            buffer: Cow::Owned(buffer),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&frame).map_err(gif_error)?;
+    }
+
+    Ok(())
+}
+
+/// Save `frames` as a GIF at `path`, see [`write_gif`]
+pub fn save_gif(path: &Path, frames: impl IntoIterator<Item = Image>, fps: f64) -> io::Result<()> {
+    if extension(path) != "gif" {
+        return Err(unsupported(path));
+    }
+
+    let mut out = BufWriter::new(File::create(path)?);
+    write_gif(&mut out, frames, fps)?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_text() {
+        let palette = Palette::new().with("#", Color::Red).with("o", Color::Blue);
+        let image = Image::from_text("#.\no", &palette, 2);
+
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.get(1, 1), Some(rgb(Color::Red)));
+        assert_eq!(image.get(2, 0), Some(rgb(BACKGROUND)));
+        assert_eq!(image.get(0, 3), Some(rgb(Color::Blue)));
+        // the short second line is padded out
+        assert_eq!(image.get(3, 3), Some(rgb(BACKGROUND)));
+        assert_eq!(image.get(4, 0), None);
+    }
+
+    #[test]
+    fn test_from_grid_and_ppm() {
+        let grid = Grid::parse("ab", |c| c).unwrap();
+        let image = Image::from_grid(&grid, 1, |&c| match c {
+            'a' => Color::TrueColor { r: 1, g: 2, b: 3 },
+            _ => Color::White,
+        });
+
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert_eq!(ppm, b"P6\n2 1\n255\n\x01\x02\x03\xe5\xe5\xe5");
+    }
+
+    #[test]
+    fn test_resize() {
+        let image = Image::new(2, 2, Color::White);
+        let resized = image.resize(3, 1);
+
+        assert_eq!((resized.width(), resized.height()), (3, 1));
+        assert_eq!(resized.get(1, 0), Some(rgb(Color::White)));
+        assert_eq!(resized.get(2, 0), Some(rgb(BACKGROUND)));
+    }
+
+    #[test]
+    fn test_png_and_gif() {
+        let palette = Palette::new().with("#", Color::Green);
+        let frames = ["#..\n.#.", "..#\n.#."].map(|f| Image::from_text(f, &palette, 3));
+
+        let mut png = Vec::new();
+        frames[0].write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        let mut gif = Vec::new();
+        write_gif(&mut gif, frames, 10.0).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        // the logical screen size comes after the signature, little endian
+        assert_eq!(&gif[6..10], &[9, 0, 6, 0]);
+
+        assert!(write_gif(&mut Vec::new(), [], 10.0).is_err());
+    }
+}
@@ -0,0 +1,377 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A path cost that can be summed and compared, with [`Default`] as zero
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// A node waiting in a priority queue, ordered so that [`BinaryHeap`] pops the lowest estimate first
+struct Queued<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // between equal estimates prefer the node that has got further, as it's closer to the goal
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+/// Follow parents back from `goal` to the node without one
+fn build_path<N: Clone + Eq + Hash, C>(parents: &HashMap<N, (C, Option<N>)>, goal: N) -> Vec<N> {
+    let mut path = vec![goal];
+
+    while let Some((_, Some(parent))) = parents.get(&path[path.len() - 1]) {
+        path.push(parent.clone());
+    }
+
+    path.reverse();
+    path
+}
+
+/// Breadth-first search from `start` to the nearest node that satisfies `success`.
+///
+/// Returns the path, including `start` and the goal, and the number of steps taken.
+pub fn bfs<N, FN, IN, FG>(start: N, mut successors: FN, mut success: FG) -> Option<(Vec<N>, usize)>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut parents = HashMap::from([(start.clone(), (0, None))]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, steps)) = queue.pop_front() {
+        if success(&node) {
+            return Some((build_path(&parents, node), steps));
+        }
+
+        for next in successors(&node) {
+            if let Entry::Vacant(e) = parents.entry(next.clone()) {
+                e.insert((steps + 1, Some(node.clone())));
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Find the cheapest path from `start` to a node that satisfies `success`, where `successors` gives
+/// each neighbouring node along with the cost of moving there.
+///
+/// Returns the path, including `start` and the goal, and its total cost.
+pub fn dijkstra<N, C, FN, IN, FG>(start: N, successors: FN, success: FG) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` estimate of the remaining cost to a goal.
+///
+/// The heuristic must never overestimate, otherwise the path found may not be the cheapest.
+pub fn astar<N, C, FN, IN, FH, FG>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FG,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut parents = HashMap::from([(start.clone(), (C::default(), None))]);
+    let mut queue = BinaryHeap::from([Queued {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // a cheaper way here was found after this was queued
+        if parents[&node].0 < cost {
+            continue;
+        }
+
+        if success(&node) {
+            return Some((build_path(&parents, node), cost));
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+
+            if matches!(parents.get(&next), Some(&(known, _)) if known <= next_cost) {
+                continue;
+            }
+
+            parents.insert(next.clone(), (next_cost, Some(node.clone())));
+            queue.push(Queued {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    None
+}
+
+/// Every equally cheap path found by [`dijkstra_all`]
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    cost: C,
+    goals: Vec<N>,
+    predecessors: HashMap<N, (C, Vec<N>)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> ShortestPaths<N, C> {
+    /// The cost of every one of the paths
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    /// Every goal that can be reached at the cheapest cost
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// Every node on at least one of the paths, including the start and goals
+    pub fn nodes(&self) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack = self.goals.clone();
+
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.predecessors[&node].1.iter().cloned());
+            }
+        }
+
+        nodes
+    }
+
+    /// The number of distinct paths, across all goals
+    pub fn count(&self) -> usize {
+        // costs only go up along a path, so a node's predecessors are always counted before it
+        let mut nodes = self.nodes().into_iter().collect::<Vec<_>>();
+        nodes.sort_by_key(|node| self.predecessors[node].0);
+
+        let mut counts = HashMap::new();
+
+        for node in nodes {
+            let predecessors = &self.predecessors[&node].1;
+            let count = if predecessors.is_empty() {
+                1
+            } else {
+                predecessors.iter().map(|p| counts[p]).sum()
+            };
+
+            counts.insert(node, count);
+        }
+
+        self.goals.iter().map(|goal| counts[goal]).sum()
+    }
+}
+
+/// Like [`dijkstra`], but finds every path that ties for the cheapest, to every goal at that cost.
+///
+/// Every step must cost more than zero.
+pub fn dijkstra_all<N, C, FN, IN, FG>(
+    start: N,
+    mut successors: FN,
+    mut success: FG,
+) -> Option<ShortestPaths<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut predecessors = HashMap::from([(start.clone(), (C::default(), Vec::new()))]);
+    let mut queue = BinaryHeap::from([Queued {
+        estimate: C::default(),
+        cost: C::default(),
+        node: start,
+    }]);
+    let mut goals = Vec::new();
+    let mut best = None;
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+
+        if predecessors[&node].0 < cost {
+            continue;
+        }
+
+        if success(&node) {
+            best = Some(cost);
+            goals.push(node);
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+
+            match predecessors.get_mut(&next) {
+                Some((known, _)) if *known < next_cost => continue,
+                Some((known, nodes)) if *known == next_cost => nodes.push(node.clone()),
+                _ => {
+                    predecessors.insert(next.clone(), (next_cost, vec![node.clone()]));
+                    queue.push(Queued {
+                        estimate: next_cost,
+                        cost: next_cost,
+                        node: next,
+                    });
+                }
+            }
+        }
+    }
+
+    best.map(|cost| ShortestPaths {
+        cost,
+        goals,
+        predecessors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    const MAZE: &str = "S..#....\n.#.#.##.\n.#...#..\n.####.#.\n......#E";
+
+    fn open(maze: &Grid<char>) -> impl FnMut(&(usize, usize)) -> Vec<(usize, usize)> + '_ {
+        |&pos| {
+            maze.neighbours4(pos)
+                .filter(|&next| maze[next] != '#')
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = Grid::parse(MAZE, |c| c).unwrap();
+        let (path, steps) = bfs((0, 0), open(&maze), |&pos| maze[pos] == 'E').unwrap();
+
+        assert_eq!(steps, 15);
+        assert_eq!(path.len(), 16);
+        assert_eq!(path[0], (0, 0));
+        assert_eq!(path[15], (7, 4));
+        assert!(path
+            .windows(2)
+            .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1));
+
+        assert_eq!(bfs((0, 0), open(&maze), |_| false), None);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // going straight down is the fewest steps, but going around is cheaper
+        let costs = Grid::parse("1111\n9991\n1111", |c| c.to_digit(10).unwrap()).unwrap();
+        let successors = |&pos: &(usize, usize)| {
+            costs
+                .neighbours4(pos)
+                .map(|next| (next, costs[next]))
+                .collect::<Vec<_>>()
+        };
+        let goal = (0, 2);
+
+        let (path, cost) = dijkstra((0, 0), successors, |&pos| pos == goal).unwrap();
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.last(), Some(&goal));
+
+        let manhattan =
+            |pos: &(usize, usize)| (pos.0.abs_diff(goal.0) + pos.1.abs_diff(goal.1)) as u32;
+        let (astar_path, cost) = astar((0, 0), successors, manhattan, |&pos| pos == goal).unwrap();
+        assert_eq!(cost, 8);
+        assert_eq!(astar_path, path);
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        // every path that only moves right or down is as short as any other
+        let grid = Grid::new(3, 3, '.');
+        let paths = dijkstra_all(
+            (0, 0),
+            |&pos| {
+                grid.neighbours4(pos)
+                    .map(|next| (next, 1))
+                    .collect::<Vec<_>>()
+            },
+            |&pos| pos == (2, 2),
+        )
+        .unwrap();
+
+        assert_eq!(paths.cost(), 4);
+        assert_eq!(paths.goals(), &[(2, 2)]);
+        assert_eq!(paths.count(), 6);
+        assert_eq!(paths.nodes().len(), 9);
+    }
+
+    #[test]
+    fn test_dijkstra_all_multiple_goals() {
+        let maze = Grid::parse(MAZE, |c| c).unwrap();
+        let paths = dijkstra_all(
+            (0, 0),
+            |&pos| open(&maze)(&pos).into_iter().map(|next| (next, 1)),
+            |&pos| pos.1 == 4,
+        )
+        .unwrap();
+
+        assert_eq!(paths.cost(), 4);
+        assert_eq!(paths.goals(), &[(0, 4)]);
+        assert_eq!(paths.count(), 1);
+        assert_eq!(
+            paths.nodes(),
+            HashSet::from([(0, 0), (0, 1), (0, 2), (0, 3), (0, 4)])
+        );
+
+        let paths = dijkstra_all(
+            (2, 2),
+            |&pos| open(&maze)(&pos).into_iter().map(|next| (next, 1)),
+            |&pos| pos == (0, 0) || pos == (4, 0),
+        )
+        .unwrap();
+
+        assert_eq!(paths.cost(), 4);
+        assert_eq!(paths.count(), 2);
+    }
+}
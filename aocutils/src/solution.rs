@@ -0,0 +1,292 @@
+use std::{any::TypeId, fmt::Display};
+
+#[cfg(feature = "viz")]
+use crate::viz::Animation;
+use crate::{
+    bench::{bench_solution, Timings},
+    Result,
+};
+
+/// A solution to a single day's puzzle.
+///
+/// `parse` turns the raw puzzle input into whatever the day wants to work with, which is then
+/// shared between both parts. Parts that haven't been solved yet can use [`Unsolved`] as their
+/// answer type. Each step can fail with an [`Error`](crate::Error), which the runner reports.
+///
+/// Days that simulate something can also override `animate` to show it with `aoc animate`, which
+/// needs aocutils' `viz` feature, and days that work something out along the way that helps make
+/// sense of odd inputs can override `explain` to print it with `aoc explain`.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+    type Part1: Display + 'static;
+    type Part2: Display + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+
+    #[cfg(feature = "viz")]
+    fn animate(_input: &Self::Input) -> Option<Animation> {
+        None
+    }
+
+    fn explain(_input: &Self::Input) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// Answer type for a part that doesn't have a solution yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+/// The answers from running both parts of a day, `None` is used for unsolved parts
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+pub(crate) fn is_unsolved<T: 'static>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<Unsolved>()
+}
+
+fn answer<T: Display + 'static>(value: T) -> Option<String> {
+    if is_unsolved::<T>() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+fn run_solution<S: Solution>(input: &str) -> Result<Answers> {
+    let parsed = S::parse(input)?;
+
+    Ok(Answers {
+        part1: answer(S::part1(&parsed)?),
+        part2: answer(S::part2(&parsed)?),
+    })
+}
+
+/// A type-erased [`Solution`] so that days can be stored together in a [`Registry`]
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    run: fn(&str) -> Result<Answers>,
+    bench: fn(&str, usize) -> Result<Timings>,
+    #[cfg(feature = "viz")]
+    animate: fn(&str) -> Result<Option<Animation>>,
+    explain: fn(&str) -> Result<Option<String>>,
+}
+
+impl Day {
+    pub fn new<S: Solution>() -> Self {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            run: run_solution::<S>,
+            bench: bench_solution::<S>,
+            #[cfg(feature = "viz")]
+            animate: |input| Ok(S::animate(&S::parse(input)?)),
+            explain: |input| S::explain(&S::parse(input)?),
+        }
+    }
+
+    pub fn run(&self, input: &str) -> Result<Answers> {
+        (self.run)(input)
+    }
+
+    /// Time parsing and each solved part separately over `runs` repeats
+    pub fn bench(&self, input: &str, runs: usize) -> Result<Timings> {
+        (self.bench)(input, runs)
+    }
+
+    /// The day's animation for `input`, if it has one
+    #[cfg(feature = "viz")]
+    pub fn animate(&self, input: &str) -> Result<Option<Animation>> {
+        (self.animate)(input)
+    }
+
+    /// The day's explanation of how it got its answers for `input`, if it has one
+    pub fn explain(&self, input: &str) -> Result<Option<String>> {
+        (self.explain)(input)
+    }
+}
+
+/// Collection of days that can be looked up by year and day number
+#[derive(Debug, Default)]
+pub struct Registry {
+    days: Vec<Day>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Add a solution to the registry, keeping days sorted by year then day
+    pub fn register<S: Solution>(&mut self) -> &mut Self {
+        let day = Day::new::<S>();
+
+        match self
+            .days
+            .binary_search_by_key(&(day.year, day.day), |d| (d.year, d.day))
+        {
+            Ok(_) => panic!("{} day {} is already registered", day.year, day.day),
+            Err(idx) => self.days.insert(idx, day),
+        }
+
+        self
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Day> {
+        self.days.iter().find(|d| d.year == year && d.day == day)
+    }
+
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &Day> {
+        self.days.iter().filter(move |d| d.year == year)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Day> {
+        self.days.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    struct Example;
+
+    impl Solution for Example {
+        const YEAR: u16 = 2024;
+        const DAY: u8 = 1;
+
+        type Input = Vec<i64>;
+        type Part1 = i64;
+        type Part2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .lines()
+                .map(|l| {
+                    l.parse()
+                        .map_err(|_| Error::parse_at(input, l, "expected a number"))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<i64> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(_: &Self::Input) -> Result<Unsolved> {
+            Ok(Unsolved)
+        }
+    }
+
+    struct Other;
+
+    impl Solution for Other {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 3;
+
+        type Input = String;
+        type Part1 = String;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.to_string())
+        }
+
+        fn part1(input: &Self::Input) -> Result<String> {
+            Ok(input.to_uppercase())
+        }
+
+        fn part2(input: &Self::Input) -> Result<usize> {
+            Ok(input.len())
+        }
+
+        fn explain(input: &Self::Input) -> Result<Option<String>> {
+            Ok(Some(format!("{} chars", input.len())))
+        }
+    }
+
+    #[test]
+    fn test_run_day() {
+        let day = Day::new::<Example>();
+
+        assert_eq!((day.year, day.day), (2024, 1));
+        assert_eq!(
+            day.run("1\n2\n3").unwrap(),
+            Answers {
+                part1: Some("6".to_string()),
+                part2: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_run_day_parse_error() {
+        let day = Day::new::<Example>();
+
+        match day.run("1\nfour\n3") {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 1)),
+            r => panic!("expected a parse error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_explain_day() {
+        assert_eq!(
+            Day::new::<Other>().explain("abc").unwrap(),
+            Some("3 chars".to_string())
+        );
+        assert_eq!(Day::new::<Example>().explain("1").unwrap(), None);
+        assert!(Day::new::<Example>().explain("x").is_err());
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let timings = Day::new::<Example>().bench("1\n2\n3", 5).unwrap();
+
+        assert!(timings.parse.min <= timings.parse.median);
+        assert!(timings.parse.median <= timings.parse.p95);
+        assert!(timings.part1.is_some());
+        assert!(timings.part2.is_none());
+    }
+
+    #[test]
+    fn test_registry_lookup() {
+        let mut registry = Registry::new();
+        registry.register::<Example>().register::<Other>();
+
+        assert_eq!(
+            registry.iter().map(|d| (d.year, d.day)).collect::<Vec<_>>(),
+            vec![(2022, 3), (2024, 1)]
+        );
+        assert_eq!(registry.year(2024).count(), 1);
+        assert!(registry.get(2022, 3).is_some());
+        assert!(registry.get(2022, 4).is_none());
+        assert_eq!(
+            registry.get(2022, 3).unwrap().run("abc").unwrap().part1,
+            Some("ABC".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_registry_duplicate() {
+        Registry::new().register::<Example>().register::<Example>();
+    }
+}
@@ -0,0 +1,468 @@
+//! Play a sequence of text frames as an animation in the terminal.
+//!
+//! While playing, space pauses, the left and right arrows step a frame at a time, page up and page
+//! down seek further, home and end jump to either end, `+` and `-` change the speed and `q` quits.
+
+#[cfg(feature = "render")]
+use std::path::Path;
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+use colored::Colorize;
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, ClearType},
+};
+
+pub use colored::Color;
+
+#[cfg(feature = "render")]
+use crate::render::{self, Image};
+use crate::{Error, Result};
+
+/// How many frames page up and page down move by
+const SEEK_FRAMES: usize = 100;
+
+/// Frames per second animations start at, unless they pick their own speed
+const DEFAULT_FPS: f64 = 10.0;
+/// The slowest and fastest animations can be played
+const MIN_FPS: f64 = 0.25;
+const MAX_FPS: f64 = 1000.0;
+
+/// Colours for the characters in a frame, anything without a colour is drawn as it is
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Palette {
+    colours: HashMap<char, Color>,
+}
+
+impl Palette {
+    pub fn new() -> Self {
+        Palette::default()
+    }
+
+    /// Draw every one of `chars` in `colour`
+    pub fn with(mut self, chars: &str, colour: Color) -> Self {
+        self.colours.extend(chars.chars().map(|c| (c, colour)));
+        self
+    }
+
+    /// The colour `c` is drawn in, if it has one
+    pub fn get(&self, c: char) -> Option<Color> {
+        self.colours.get(&c).copied()
+    }
+
+    /// Colour a line of a frame, grouping runs of the same colour to keep the escape codes down
+    pub fn paint(&self, line: &str) -> String {
+        let mut painted = String::new();
+        let mut run = String::new();
+        let mut run_colour = None;
+
+        for c in line.chars() {
+            let colour = self.colours.get(&c).copied();
+
+            if colour != run_colour && !run.is_empty() {
+                painted += &paint_run(&run, run_colour);
+                run.clear();
+            }
+
+            run.push(c);
+            run_colour = colour;
+        }
+
+        painted + &paint_run(&run, run_colour)
+    }
+}
+
+fn paint_run(run: &str, colour: Option<Color>) -> String {
+    match colour {
+        Some(colour) => run.color(colour).to_string(),
+        None => run.to_string(),
+    }
+}
+
+/// Something to do while an animation is playing, read from a key press
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    TogglePause,
+    StepForward,
+    StepBack,
+    SeekForward,
+    SeekBack,
+    Start,
+    End,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Control {
+    fn from_key(key: KeyEvent) -> Option<Self> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Control::Quit)
+            }
+            KeyCode::Char(' ') => Some(Control::TogglePause),
+            KeyCode::Right | KeyCode::Char('l') => Some(Control::StepForward),
+            KeyCode::Left | KeyCode::Char('h') => Some(Control::StepBack),
+            KeyCode::PageDown | KeyCode::Char(']') => Some(Control::SeekForward),
+            KeyCode::PageUp | KeyCode::Char('[') => Some(Control::SeekBack),
+            KeyCode::Home | KeyCode::Char('g') => Some(Control::Start),
+            KeyCode::End | KeyCode::Char('G') => Some(Control::End),
+            KeyCode::Char('+' | '=') => Some(Control::Faster),
+            KeyCode::Char('-') => Some(Control::Slower),
+            KeyCode::Char('q') | KeyCode::Esc => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Where an animation is up to. Frames are only generated as they are needed, and kept so that
+/// playback can go backwards.
+struct Playback {
+    source: Box<dyn Iterator<Item = String>>,
+    frames: Vec<String>,
+    finished: bool,
+    position: usize,
+    paused: bool,
+    fps: f64,
+}
+
+impl Playback {
+    fn new(source: Box<dyn Iterator<Item = String>>, fps: f64) -> Self {
+        Playback {
+            source,
+            frames: Vec::new(),
+            finished: false,
+            position: 0,
+            paused: false,
+            fps,
+        }
+    }
+
+    /// Generate frames up to `index`, returning whether it exists
+    fn load(&mut self, index: usize) -> bool {
+        while !self.finished && self.frames.len() <= index {
+            match self.source.next() {
+                Some(frame) => self.frames.push(frame),
+                None => self.finished = true,
+            }
+        }
+
+        index < self.frames.len()
+    }
+
+    /// Move to `index`, or as close to it as there are frames
+    fn seek(&mut self, index: usize) {
+        self.load(index);
+        self.position = index.min(self.frames.len().saturating_sub(1));
+    }
+
+    /// Move on to the next frame if playing, pausing at the end
+    fn tick(&mut self) {
+        if self.paused {
+            return;
+        }
+
+        if self.load(self.position + 1) {
+            self.position += 1;
+        } else {
+            self.paused = true;
+        }
+    }
+
+    /// Returns `false` if playback should stop
+    fn apply(&mut self, control: Control) -> bool {
+        match control {
+            Control::TogglePause => self.paused = !self.paused,
+            Control::StepForward => {
+                self.paused = true;
+                self.seek(self.position + 1);
+            }
+            Control::StepBack => {
+                self.paused = true;
+                self.seek(self.position.saturating_sub(1));
+            }
+            Control::SeekForward => self.seek(self.position + SEEK_FRAMES),
+            Control::SeekBack => self.seek(self.position.saturating_sub(SEEK_FRAMES)),
+            Control::Start => self.seek(0),
+            Control::End => self.seek(usize::MAX),
+            Control::Faster => self.fps = (self.fps * 2.0).min(MAX_FPS),
+            Control::Slower => self.fps = (self.fps / 2.0).max(MIN_FPS),
+            Control::Quit => return false,
+        }
+
+        true
+    }
+
+    fn frame(&self) -> &str {
+        &self.frames[self.position]
+    }
+
+    fn status(&self) -> String {
+        let total = match self.finished {
+            true => self.frames.len().to_string(),
+            false => "?".to_string(),
+        };
+
+        format!(
+            "frame {}/{}  {} fps{}  [space] pause  [←/→] step  [PgUp/PgDn] seek  [Home/End] jump  [+/-] speed  [q] quit",
+            self.position + 1,
+            total,
+            self.fps,
+            if self.paused { "  paused" } else { "" },
+        )
+    }
+}
+
+/// A sequence of frames to play in the terminal, each a multi-line string
+pub struct Animation {
+    frames: Box<dyn Iterator<Item = String>>,
+    palette: Palette,
+    fps: f64,
+}
+
+impl Animation {
+    /// Frames can be generated lazily, they are only created as playback reaches them
+    pub fn new<I>(frames: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+        I::IntoIter: 'static,
+    {
+        Animation {
+            frames: Box::new(frames.into_iter()),
+            palette: Palette::new(),
+            fps: DEFAULT_FPS,
+        }
+    }
+
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Frames per second to start playing at, 10 by default. It's kept to the same range as the
+    /// speed can be changed to while playing
+    pub fn fps(mut self, fps: f64) -> Self {
+        self.fps = if fps.is_nan() {
+            DEFAULT_FPS
+        } else {
+            fps.clamp(MIN_FPS, MAX_FPS)
+        };
+        self
+    }
+
+    /// Only keep the frames from `from` up to, but not including, `to`
+    pub fn slice(mut self, from: usize, to: Option<usize>) -> Self {
+        let frames = self.frames.skip(from);
+        self.frames = match to {
+            Some(to) => Box::new(frames.take(to.saturating_sub(from))),
+            None => Box::new(frames),
+        };
+        self
+    }
+
+    pub fn into_frames(self) -> impl Iterator<Item = String> {
+        self.frames
+    }
+
+    /// Render each frame as an image, see [`Image::from_text`]
+    #[cfg(feature = "render")]
+    pub fn images(self, scale: usize) -> impl Iterator<Item = Image> {
+        let palette = self.palette;
+        self.frames
+            .map(move |frame| Image::from_text(&frame, &palette, scale))
+    }
+
+    /// Save as a GIF that plays at the animation's own speed, see [`render::write_gif`]
+    #[cfg(feature = "render")]
+    pub fn save_gif(self, path: &Path, scale: usize) -> io::Result<()> {
+        let fps = self.fps;
+        render::save_gif(path, self.images(scale), fps)
+    }
+
+    /// Play the animation in place in the terminal until it is quit
+    pub fn play(self) -> Result<()> {
+        let mut playback = Playback::new(self.frames, self.fps);
+        if !playback.load(0) {
+            return Err(Error::invalid_state("the animation has no frames"));
+        }
+
+        let mut stdout = io::stdout();
+        terminal::enable_raw_mode()?;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+        let result = run(&mut stdout, &mut playback, &self.palette);
+
+        // always try to put the terminal back, even if playing failed
+        let restored = execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)
+            .and_then(|_| terminal::disable_raw_mode());
+
+        result.and(restored.map_err(Error::from))
+    }
+}
+
+fn run(out: &mut impl Write, playback: &mut Playback, palette: &Palette) -> Result<()> {
+    let mut next_frame = Instant::now();
+
+    loop {
+        draw(out, playback, palette)?;
+
+        let frame_time = Duration::from_secs_f64(1.0 / playback.fps);
+        let now = Instant::now();
+        if next_frame < now {
+            next_frame = now + frame_time;
+        }
+
+        let timeout = match playback.paused {
+            true => Duration::from_secs(60),
+            false => next_frame - now,
+        };
+
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if let Some(control) = Control::from_key(key) {
+                    if !playback.apply(control) {
+                        return Ok(());
+                    }
+                }
+            }
+        } else if !playback.paused {
+            playback.tick();
+            next_frame += frame_time;
+        }
+    }
+}
+
+fn draw(out: &mut impl Write, playback: &Playback, palette: &Palette) -> Result<()> {
+    let (columns, rows) = terminal::size()?;
+    let (columns, rows) = (columns as usize, rows as usize);
+
+    queue!(out, cursor::MoveTo(0, 0))?;
+
+    // leave the last row for the status line
+    for line in playback.frame().lines().take(rows.saturating_sub(1)) {
+        let line = line.chars().take(columns).collect::<String>();
+        queue!(
+            out,
+            Print(palette.paint(&line)),
+            terminal::Clear(ClearType::UntilNewLine),
+            Print("\r\n")
+        )?;
+    }
+
+    let status = playback.status().chars().take(columns).collect::<String>();
+    queue!(
+        out,
+        Print(status),
+        terminal::Clear(ClearType::FromCursorDown)
+    )?;
+
+    Ok(out.flush()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn playback(n: usize) -> Playback {
+        Playback::new(Box::new((0..n).map(|i| i.to_string())), 10.0)
+    }
+
+    #[test]
+    fn test_playback_ticks_to_end() {
+        let mut playback = playback(3);
+        playback.load(0);
+
+        playback.tick();
+        assert_eq!(playback.frame(), "1");
+        playback.tick();
+        playback.tick();
+        assert_eq!(playback.frame(), "2");
+        assert!(playback.paused);
+        assert!(playback.status().starts_with("frame 3/3  10 fps  paused"));
+    }
+
+    #[test]
+    fn test_playback_controls() {
+        let mut playback = playback(250);
+        playback.load(0);
+
+        assert!(playback.apply(Control::StepBack));
+        assert_eq!(playback.frame(), "0");
+        assert!(playback.paused);
+
+        playback.apply(Control::StepForward);
+        playback.apply(Control::SeekForward);
+        assert_eq!(playback.frame(), "101");
+        // frames past the ones watched so far haven't been generated yet
+        assert!(playback.status().starts_with("frame 102/?"));
+
+        playback.apply(Control::End);
+        assert_eq!(playback.frame(), "249");
+        playback.apply(Control::SeekBack);
+        assert_eq!(playback.frame(), "149");
+        playback.apply(Control::Start);
+        assert_eq!(playback.frame(), "0");
+
+        playback.apply(Control::TogglePause);
+        playback.apply(Control::Faster);
+        assert!(!playback.paused);
+        assert_eq!(playback.fps, 20.0);
+
+        assert!(!playback.apply(Control::Quit));
+    }
+
+    #[test]
+    fn test_controls_from_keys() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        assert_eq!(
+            Control::from_key(key(KeyCode::Char(' '))),
+            Some(Control::TogglePause)
+        );
+        assert_eq!(
+            Control::from_key(key(KeyCode::Right)),
+            Some(Control::StepForward)
+        );
+        assert_eq!(
+            Control::from_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(Control::Quit)
+        );
+        assert_eq!(Control::from_key(key(KeyCode::Char('x'))), None);
+    }
+
+    #[test]
+    fn test_palette_paint() {
+        colored::control::set_override(true);
+        let palette = Palette::new()
+            .with("#", Color::Red)
+            .with("O@", Color::Green);
+
+        assert_eq!(
+            palette.paint("##.O@"),
+            format!("{}.{}", "##".red(), "O@".green())
+        );
+        assert_eq!(Palette::new().paint("##.O@"), "##.O@");
+    }
+
+    #[test]
+    fn test_animation_fps() {
+        let fps = |fps| Animation::new(Vec::new()).fps(fps).fps;
+
+        assert_eq!(fps(30.0), 30.0);
+        assert_eq!(fps(0.0), MIN_FPS);
+        assert_eq!(fps(-5.0), MIN_FPS);
+        assert_eq!(fps(f64::INFINITY), MAX_FPS);
+        assert_eq!(fps(f64::NAN), DEFAULT_FPS);
+    }
+}